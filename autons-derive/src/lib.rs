@@ -0,0 +1,77 @@
+//! Derive macros for [`autons`](https://docs.rs/autons).
+//!
+//! These macros are re-exported by `autons` when its `derive` feature is enabled, and should not
+//! be used from this crate directly.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Path, parse_macro_input};
+
+/// Derives an implementation of `SelectCompete` that uses the default for every hook.
+///
+/// Routes can be listed using `#[autonomous_route(...)]` attributes on the struct. If any are
+/// present, an inherent `compete_with_routes` method is also generated, which runs the robot
+/// using a `SimpleSelect` over those routes. Each route is named after the last segment of its
+/// path.
+///
+/// # Examples
+///
+/// ```ignore
+/// #[derive(SelectCompete)]
+/// #[autonomous_route(Robot::left)]
+/// #[autonomous_route(Robot::right)]
+/// struct Robot {}
+///
+/// impl Robot {
+///     async fn left(&mut self) {}
+///     async fn right(&mut self) {}
+/// }
+///
+/// #[vexide::main]
+/// async fn main(peripherals: Peripherals) {
+///     Robot {}.compete_with_routes(peripherals.display).await;
+/// }
+/// ```
+#[proc_macro_derive(SelectCompete, attributes(autonomous_route))]
+pub fn derive_select_compete(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let routes = match input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("autonomous_route"))
+        .map(|attr| attr.parse_args::<Path>())
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(routes) => routes,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    let compete_with_routes = (!routes.is_empty()).then(|| {
+        quote! {
+            impl #impl_generics #name #type_generics #where_clause {
+                /// Runs the competition lifecycle using a `SimpleSelect` over this robot's
+                /// autonomous routes.
+                pub async fn compete_with_routes(self, display: ::vexide::display::Display) -> ! {
+                    ::autons::compete::SelectCompeteExt::compete(
+                        self,
+                        ::autons::simple::SimpleSelect::new(
+                            display,
+                            [#(::autons::simple::route!(#routes)),*],
+                        ),
+                    )
+                    .await
+                }
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::autons::compete::SelectCompete for #name #type_generics #where_clause {}
+
+        #compete_with_routes
+    }
+    .into()
+}
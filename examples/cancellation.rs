@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use autons::{
+    cancel::{Cancellable, RouteContext},
+    prelude::*,
+    simple::{SimpleSelect, route},
+};
+use vexide::{prelude::*, smart::motor::BrakeMode};
+
+struct Robot {
+    left_motor: Motor,
+    right_motor: Motor,
+}
+
+impl Robot {
+    async fn drive_forward(&mut self) {
+        _ = self.left_motor.set_voltage(6.0);
+        _ = self.right_motor.set_voltage(6.0);
+
+        sleep(Duration::from_secs(5)).await;
+    }
+
+    fn stop(&mut self) {
+        _ = self.left_motor.brake(BrakeMode::Brake);
+        _ = self.right_motor.brake(BrakeMode::Brake);
+    }
+
+    async fn route_1(&mut self) {
+        // Handlers can't touch the robot, but they run as soon as the route is interrupted.
+        RouteContext::current()
+            .token()
+            .on_cancel(|| println!("Route 1 was interrupted"));
+
+        self.drive_forward().await;
+        self.stop();
+    }
+
+    async fn route_2(&mut self) {}
+}
+
+impl SelectCompete for Robot {
+    // If autonomous ends during `drive_forward`, the route is dropped before it can stop the
+    // motors, so stop them here before the next phase starts.
+    async fn route_cancelled(&mut self) {
+        self.stop();
+    }
+}
+
+#[vexide::main]
+async fn main(peripherals: Peripherals) {
+    let robot = Robot {
+        left_motor: Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward),
+        right_motor: Motor::new(peripherals.port_2, Gearset::Green, Direction::Reverse),
+    };
+
+    robot
+        .compete(Cancellable::new(SimpleSelect::new(
+            peripherals.display,
+            [route!(Robot::route_1), route!(Robot::route_2)],
+        )))
+        .await;
+}
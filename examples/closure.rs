@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use autons::{
+    prelude::*,
+    simple::{Route, SimpleSelect, compact_route, route},
+};
+use vexide::prelude::*;
+
+#[derive(Debug, Clone, Copy)]
+struct PathPoint {
+    x: f64,
+    y: f64,
+}
+
+/// Reads a path from the SD card, with one `x,y` point per line.
+fn load_path(path: &str) -> Vec<PathPoint> {
+    std::fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let (x, y) = line.split_once(',')?;
+
+            Some(PathPoint {
+                x: x.trim().parse().ok()?,
+                y: y.trim().parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+struct Robot {}
+
+impl Robot {
+    async fn move_to(&mut self, point: PathPoint) {
+        println!("Moving to ({}, {})", point.x, point.y);
+        sleep(Duration::from_millis(500)).await;
+    }
+
+    async fn follow(&mut self, path: &[PathPoint]) {
+        for &point in path {
+            self.move_to(point).await;
+        }
+    }
+
+    async fn route_1(&mut self) {}
+}
+
+impl SelectCompete for Robot {}
+
+#[vexide::main]
+async fn main(peripherals: Peripherals) {
+    let robot = Robot {};
+    let path = load_path("path.txt");
+
+    robot
+        .compete(SimpleSelect::new(
+            peripherals.display,
+            [
+                route!("Route 1", Robot::route_1),
+                Route::from_closure("Follow Path", move |robot: &mut Robot| {
+                    let path = path.clone();
+                    Box::pin(async move { robot.follow(&path).await })
+                }),
+                compact_route!("Drive Forward", |robot: &mut Robot| async {
+                    robot.move_to(PathPoint { x: 0.0, y: 24.0 }).await;
+                }),
+            ],
+        ))
+        .await;
+}
@@ -0,0 +1,39 @@
+use autons::{
+    prelude::*,
+    simple::{SimpleSelect, route},
+};
+use vexide::prelude::*;
+
+struct Robot {
+    selector: SimpleSelect<Robot, 2>,
+}
+
+impl Robot {
+    async fn route_1(&mut self) {}
+    async fn route_2(&mut self) {}
+}
+
+impl SelectCompete for Robot {
+    // Ignore touches while plugged into field control, so that the selection isn't changed by
+    // accident when the cables are connected.
+    async fn connected(&mut self) {
+        self.selector.set_locked(true);
+    }
+
+    async fn disconnected(&mut self) {
+        self.selector.set_locked(false);
+    }
+}
+
+#[vexide::main]
+async fn main(peripherals: Peripherals) {
+    let selector = SimpleSelect::builder([route!(Robot::route_1), route!(Robot::route_2)])
+        .header("Locks when connected")
+        .build(peripherals.display);
+
+    let robot = Robot {
+        selector: selector.clone(),
+    };
+
+    robot.compete(selector).await;
+}
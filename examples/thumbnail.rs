@@ -0,0 +1,38 @@
+use autons::{
+    prelude::*,
+    simple::{SimpleSelect, THEME_DARK, Thumbnail, route},
+};
+use vexide::prelude::*;
+
+/// An 80x80 field diagram of the rush route, stored as raw RGB pixels.
+const RUSH_DIAGRAM: Thumbnail = Thumbnail::new(80, 80, include_bytes!("thumbnail.rgb"));
+
+struct Robot {}
+
+impl Robot {
+    async fn rush(&mut self) {}
+    async fn safe(&mut self) {}
+}
+
+impl SelectCompete for Robot {}
+
+#[vexide::main]
+async fn main(peripherals: Peripherals) {
+    let robot = Robot {};
+
+    robot
+        .compete(SimpleSelect::new_with_detail(
+            peripherals.display,
+            [
+                route!(
+                    "Rush",
+                    Robot::rush,
+                    description: "Center goal rush.",
+                    thumbnail: RUSH_DIAGRAM,
+                ),
+                route!("Safe", Robot::safe, description: "Scores the preload only."),
+            ],
+            THEME_DARK,
+        ))
+        .await;
+}
@@ -0,0 +1,389 @@
+//! Cooperative cancellation for autonomous routes.
+//!
+//! When the competition mode changes away from autonomous, vexide's competition runtime drops the
+//! running route future wherever it was last suspended. This can leave mechanisms in whatever state
+//! the route last put them in. This module provides the [`Cancellable`] selector wrapper, which
+//! hands each route a [`CancelToken`] (through [`RouteContext::current`]) that is triggered when
+//! the route is interrupted, allowing it to clean up after itself.
+//!
+//! # Cancellation Timing
+//!
+//! A [`Cancellable`] selector cancels its token in two situations:
+//!
+//! - When it observes a change in competition status while the route is still running. The route
+//!   continues to be polled afterwards, so it may finish any cleanup asynchronously.
+//! - When the route future is dropped before completing. This is what happens when vexide's
+//!   competition runtime switches modes, and in this case the route will never be polled again.
+//!
+//! The status is checked every time the route is polled, so the first case only applies when the
+//! route is polled again after the change. Under vexide's competition runtime, the route future is
+//! usually dropped as soon as the mode changes, so the second case is the common one.
+//!
+//! In the latter case, the route can only react through synchronous handlers registered with
+//! [`CancelToken::on_cancel`]. Such handlers are `'static` and run after the route has lost its
+//! borrow of the robot, so cleanup that needs `&mut robot`, such as stopping the drivetrain,
+//! belongs in [`SelectCompete::route_cancelled`]. It runs with the robot at the start of the next
+//! competition phase whenever the autonomous route didn't finish.
+//!
+//! [`SelectCompete::route_cancelled`]: crate::compete::SelectCompete::route_cancelled
+//!
+//! # Examples
+//!
+//! ```ignore
+//! impl Robot {
+//!     async fn route_1(&mut self) {
+//!         RouteContext::current()
+//!             .token()
+//!             .on_cancel(|| println!("Route 1 was interrupted"));
+//!
+//!         self.drive_forward().await;
+//!         self.drivetrain.stop();
+//!     }
+//! }
+//!
+//! impl SelectCompete for Robot {
+//!     // The route was dropped mid-drive, so stop moving before the next phase starts.
+//!     async fn route_cancelled(&mut self) {
+//!         self.drivetrain.stop();
+//!     }
+//! }
+//!
+//! robot.compete(Cancellable::new(SimpleSelect::new(
+//!     peripherals.display,
+//!     [route!("Route 1", Robot::route_1)],
+//! )));
+//! ```
+
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    future::{Future, poll_fn},
+    pin::pin,
+    rc::Rc,
+    task::{Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use vexide::{competition, time::sleep};
+
+#[cfg(feature = "simple")]
+use crate::simple::{Alliance, Route, RouteId};
+use crate::{Selector, SelectorStatus, SelectorTelemetry};
+
+/// How often a [`Cancellable`] selector wakes an idle route to check for competition status
+/// changes.
+const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+thread_local! {
+    static CURRENT_CONTEXT: RefCell<Option<RouteContext>> = const { RefCell::new(None) };
+}
+
+#[derive(Default)]
+struct CancelTokenInner {
+    cancelled: Cell<bool>,
+    wakers: RefCell<Vec<Waker>>,
+    handlers: RefCell<Vec<Box<dyn FnOnce()>>>,
+}
+
+/// A shared flag signalling that a route should stop what it is doing.
+///
+/// Cloning a token produces another handle to the same flag, so cancelling any clone cancels all
+/// of them.
+#[derive(Default, Clone)]
+pub struct CancelToken {
+    inner: Rc<CancelTokenInner>,
+}
+
+impl CancelToken {
+    /// Creates a new token that has not yet been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the token.
+    ///
+    /// This wakes any tasks waiting on [`CancelToken::cancelled`] and runs every handler
+    /// registered with [`CancelToken::on_cancel`]. Cancelling an already cancelled token does
+    /// nothing.
+    pub fn cancel(&self) {
+        if self.inner.cancelled.replace(true) {
+            return;
+        }
+
+        for waker in self.inner.wakers.take() {
+            waker.wake();
+        }
+
+        for handler in self.inner.handlers.take() {
+            handler();
+        }
+    }
+
+    /// Returns `true` if the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.get()
+    }
+
+    /// Waits until the token is cancelled.
+    pub async fn cancelled(&self) {
+        poll_fn(|cx| {
+            if self.is_cancelled() {
+                Poll::Ready(())
+            } else {
+                self.inner.wakers.borrow_mut().push(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+        .await;
+    }
+
+    /// Runs a future until it either completes or the token is cancelled.
+    ///
+    /// Returns `None` if the token was cancelled first, in which case the future is dropped.
+    pub async fn run_until_cancelled<F: Future>(&self, future: F) -> Option<F::Output> {
+        let mut future = pin!(future);
+        let mut cancelled = pin!(self.cancelled());
+
+        poll_fn(|cx| {
+            if let Poll::Ready(output) = future.as_mut().poll(cx) {
+                Poll::Ready(Some(output))
+            } else if cancelled.as_mut().poll(cx).is_ready() {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Registers a synchronous handler that runs when the token is cancelled.
+    ///
+    /// If the token is already cancelled, the handler runs immediately.
+    pub fn on_cancel(&self, handler: impl FnOnce() + 'static) {
+        if self.is_cancelled() {
+            handler();
+        } else {
+            self.inner.handlers.borrow_mut().push(Box::new(handler));
+        }
+    }
+}
+
+/// Information available to a route while it is being run.
+///
+/// The context of the running route can be obtained through [`RouteContext::current`], or passed
+/// to the route directly by creating it with [`route_ctx!`].
+///
+/// [`route_ctx!`]: crate::route_ctx
+#[derive(Clone)]
+pub struct RouteContext {
+    token: CancelToken,
+    started_at: Instant,
+    #[cfg(feature = "simple")]
+    name: Option<Cow<'static, str>>,
+    #[cfg(feature = "simple")]
+    id: Option<RouteId>,
+    #[cfg(feature = "simple")]
+    alliance: Alliance,
+}
+
+impl Default for RouteContext {
+    fn default() -> Self {
+        Self {
+            token: CancelToken::default(),
+            started_at: Instant::now(),
+            #[cfg(feature = "simple")]
+            name: None,
+            #[cfg(feature = "simple")]
+            id: None,
+            #[cfg(feature = "simple")]
+            alliance: Alliance::None,
+        }
+    }
+}
+
+impl RouteContext {
+    /// Returns the context of the route that is currently running.
+    ///
+    /// Outside of a [`Cancellable`] selector, this returns a context whose token is never
+    /// cancelled.
+    pub fn current() -> Self {
+        CURRENT_CONTEXT.with_borrow(|context| context.clone().unwrap_or_default())
+    }
+
+    /// Returns the cancellation token for this route.
+    pub fn token(&self) -> &CancelToken {
+        &self.token
+    }
+
+    /// Returns the instant the route started running.
+    pub fn started_at(&self) -> Instant {
+        self.started_at
+    }
+
+    /// Returns how long the route has been running for.
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// Returns `true` if the robot is connected to a competition switch or field controller.
+    ///
+    /// This can be used to tell a real match apart from testing in the pits.
+    pub fn is_competition(&self) -> bool {
+        competition::is_connected()
+    }
+
+    /// Returns the name of the running route, if it was started by a selector that knows it.
+    #[cfg(feature = "simple")]
+    pub fn route_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the [ID](Route::id) of the running route, if it was started by a selector that
+    /// knows it.
+    #[cfg(feature = "simple")]
+    pub fn route_id(&self) -> Option<RouteId> {
+        self.id
+    }
+
+    /// Returns the [alliance](Route::alliance) the running route is intended for.
+    ///
+    /// This is [`Alliance::None`] if the route doesn't specify one.
+    #[cfg(feature = "simple")]
+    pub fn alliance(&self) -> Alliance {
+        self.alliance
+    }
+
+    /// Makes this the current context until the returned guard is dropped.
+    ///
+    /// The route's metadata is added to the context that was already current, so that a
+    /// cancellation token provided by an enclosing [`Cancellable`] is kept.
+    #[cfg(feature = "simple")]
+    pub(crate) fn enter_route<R, M>(route: &Route<R, M>) -> ContextScope {
+        let mut context = Self::current();
+        context.started_at = Instant::now();
+        context.name = Some(route.name.clone());
+        context.id = Some(route.id());
+        context.alliance = route.alliance;
+
+        ContextScope {
+            previous: CURRENT_CONTEXT.replace(Some(context)),
+        }
+    }
+}
+
+/// Restores the previous route context when dropped.
+#[cfg(feature = "simple")]
+pub(crate) struct ContextScope {
+    previous: Option<RouteContext>,
+}
+
+#[cfg(feature = "simple")]
+impl Drop for ContextScope {
+    fn drop(&mut self) {
+        CURRENT_CONTEXT.set(self.previous.take());
+    }
+}
+
+/// Restores the previous route context and cancels the current one when dropped.
+struct ContextGuard {
+    previous: Option<RouteContext>,
+    token: CancelToken,
+    finished: bool,
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        CURRENT_CONTEXT.set(self.previous.take());
+
+        if !self.finished {
+            self.token.cancel();
+        }
+    }
+}
+
+/// A selector wrapper that provides cancellation support to the routes it runs.
+///
+/// See the [module-level documentation](self) for details on when routes are cancelled.
+pub struct Cancellable<S> {
+    selector: S,
+}
+
+impl<S> Cancellable<S> {
+    /// Wraps a selector, providing cancellation tokens to its routes.
+    pub const fn new(selector: S) -> Self {
+        Self { selector }
+    }
+
+    /// Returns a reference to the wrapped selector.
+    pub const fn inner(&self) -> &S {
+        &self.selector
+    }
+
+    /// Returns a mutable reference to the wrapped selector.
+    pub const fn inner_mut(&mut self) -> &mut S {
+        &mut self.selector
+    }
+
+    /// Unwraps this wrapper, returning the underlying selector.
+    pub fn into_inner(self) -> S {
+        self.selector
+    }
+}
+
+impl<R, S: Selector<R>> Selector<R> for Cancellable<S> {
+    async fn run(&self, robot: &mut R) {
+        let context = RouteContext::default();
+        let mut guard = ContextGuard {
+            previous: CURRENT_CONTEXT.replace(Some(context.clone())),
+            token: context.token.clone(),
+            finished: false,
+        };
+
+        let initial_status = competition::status();
+        let mut route = pin!(self.selector.run(robot));
+        // Only keeps the task waking up while the route is idle; the status itself is checked on
+        // every poll, whatever woke it.
+        let mut ticker = pin!(sleep(STATUS_POLL_INTERVAL));
+
+        poll_fn(|cx| {
+            if !context.token.is_cancelled() {
+                if competition::status() == initial_status {
+                    while ticker.as_mut().poll(cx).is_ready() {
+                        ticker.set(sleep(STATUS_POLL_INTERVAL));
+                    }
+                } else {
+                    context.token.cancel();
+                }
+            }
+
+            route.as_mut().poll(cx)
+        })
+        .await;
+
+        guard.finished = true;
+    }
+
+    fn telemetry(&self) -> Option<&dyn SelectorTelemetry> {
+        self.selector.telemetry()
+    }
+
+    fn status(&self) -> Option<&dyn SelectorStatus<R>> {
+        self.selector.status()
+    }
+
+    fn last_changed(&self) -> Option<Instant> {
+        self.selector.last_changed()
+    }
+
+    fn reset(&mut self) {
+        self.selector.reset();
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        self.selector.selected_index()
+    }
+
+    fn name_of(&self, index: usize) -> Option<Cow<'static, str>> {
+        self.selector.name_of(index)
+    }
+}
@@ -0,0 +1,30 @@
+use vexide::color::Color;
+
+/// Extension methods for vexide's [`Color`] type.
+pub trait ColorExt {
+    /// Linearly interpolates between two colors in RGB space.
+    ///
+    /// A `t` of `0.0` returns `a`, and a `t` of `1.0` returns `b`. Values outside of this range
+    /// are clamped.
+    fn lerp(a: Color, b: Color, t: f32) -> Color;
+
+    /// Blends `c` over `background` as if `c` had the given opacity.
+    ///
+    /// An `alpha` of `0` is fully transparent and returns `background`, and an `alpha` of `255`
+    /// is fully opaque and returns `c`.
+    fn with_alpha_blend(c: Color, background: Color, alpha: u8) -> Color;
+}
+
+impl ColorExt for Color {
+    fn lerp(a: Color, b: Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let channel =
+            |a: u8, b: u8| (f32::from(a) + (f32::from(b) - f32::from(a)) * t).round() as u8;
+
+        Color::new(channel(a.r, b.r), channel(a.g, b.g), channel(a.b, b.b))
+    }
+
+    fn with_alpha_blend(c: Color, background: Color, alpha: u8) -> Color {
+        Self::lerp(background, c, f32::from(alpha) / 255.0)
+    }
+}
@@ -0,0 +1,12 @@
+//! Selectors built out of other selectors.
+//!
+//! Combinators allow multiple selectors to be used together, such as offering both a touchscreen
+//! and a controller-based selection flow at the same time.
+
+mod priority;
+#[cfg(feature = "simple")]
+mod random;
+
+pub use priority::*;
+#[cfg(feature = "simple")]
+pub use random::*;
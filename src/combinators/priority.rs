@@ -0,0 +1,249 @@
+use std::{borrow::Cow, time::Instant};
+
+use crate::{Selector, SelectorStatus, SelectorTelemetry, status_of};
+
+/// A selector that defers to whichever of two selectors was used most recently.
+///
+/// `PrioritySelect` tracks the [last selection change] of both of its children and runs the
+/// route from the child that changed most recently. If neither child has been changed (or both
+/// changed at the same instant), the first child takes priority.
+///
+/// More than two selectors can be combined by nesting `PrioritySelect`s.
+///
+/// # Examples
+///
+/// ```ignore
+/// let touch = SimpleSelect::new(peripherals.display, routes.clone());
+/// let buttons = MyButtonSelect::new(peripherals.primary_controller, routes);
+///
+/// robot.compete(PrioritySelect::new(touch, buttons)).await;
+/// ```
+///
+/// [last selection change]: Selector::last_changed
+pub struct PrioritySelect<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> PrioritySelect<A, B> {
+    /// Creates a new selector from two child selectors, in order of priority.
+    pub const fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Returns a reference to the first child selector.
+    pub const fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Returns a reference to the second child selector.
+    pub const fn second(&self) -> &B {
+        &self.second
+    }
+
+    /// Unwraps this combinator, returning both child selectors.
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A, B> PrioritySelect<A, B> {
+    /// Returns `true` if the second child was changed more recently than the first.
+    fn prefers_second<R>(&self) -> bool
+    where
+        A: Selector<R>,
+        B: Selector<R>,
+    {
+        match (self.first.last_changed(), self.second.last_changed()) {
+            (Some(first), Some(second)) => second > first,
+            (None, Some(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<R, A: Selector<R>, B: Selector<R>> Selector<R> for PrioritySelect<A, B> {
+    async fn run(&self, robot: &mut R) {
+        if self.prefers_second() {
+            self.second.run(robot).await;
+        } else {
+            self.first.run(robot).await;
+        }
+    }
+
+    fn telemetry(&self) -> Option<&dyn SelectorTelemetry> {
+        if self.prefers_second() {
+            self.second.telemetry()
+        } else {
+            self.first.telemetry()
+        }
+    }
+
+    fn status(&self) -> Option<&dyn SelectorStatus<R>> {
+        Some(self)
+    }
+
+    fn last_changed(&self) -> Option<Instant> {
+        self.first.last_changed().max(self.second.last_changed())
+    }
+
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        if self.prefers_second() {
+            self.second.selected_index()
+        } else {
+            self.first.selected_index()
+        }
+    }
+
+    fn name_of(&self, index: usize) -> Option<Cow<'static, str>> {
+        if self.prefers_second() {
+            self.second.name_of(index)
+        } else {
+            self.first.name_of(index)
+        }
+    }
+}
+
+impl<R, A: Selector<R>, B: Selector<R>> SelectorStatus<R> for PrioritySelect<A, B> {
+    fn is_noop(&self) -> bool {
+        if self.prefers_second() {
+            status_of(&self.second).is_noop()
+        } else {
+            status_of(&self.first).is_noop()
+        }
+    }
+
+    fn timed_out(&self) -> bool {
+        if self.prefers_second() {
+            status_of(&self.second).timed_out()
+        } else {
+            status_of(&self.first).timed_out()
+        }
+    }
+
+    fn redraw(&self) {
+        status_of(&self.first).redraw();
+        status_of(&self.second).redraw();
+    }
+
+    fn show_error(&self, message: &str) {
+        if self.prefers_second() {
+            status_of(&self.second).show_error(message);
+        } else {
+            status_of(&self.first).show_error(message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        pin::pin,
+        task::{Context, Poll, Waker},
+        time::{Duration, Instant},
+    };
+
+    use super::PrioritySelect;
+    use crate::Selector;
+
+    /// A selector that records its name when run and reports a manually set change time.
+    struct MockSelect {
+        name: &'static str,
+        last_changed: Cell<Option<Instant>>,
+    }
+
+    impl MockSelect {
+        fn new(name: &'static str) -> Self {
+            Self {
+                name,
+                last_changed: Cell::new(None),
+            }
+        }
+
+        fn change(&self, at: Instant) {
+            self.last_changed.set(Some(at));
+        }
+    }
+
+    impl Selector<Vec<&'static str>> for MockSelect {
+        async fn run(&self, robot: &mut Vec<&'static str>) {
+            robot.push(self.name);
+        }
+
+        fn last_changed(&self) -> Option<Instant> {
+            self.last_changed.get()
+        }
+    }
+
+    fn run_once(selector: &impl Selector<Vec<&'static str>>) -> &'static str {
+        let mut ran = Vec::new();
+        {
+            let future = pin!(selector.run(&mut ran));
+            assert_eq!(
+                future.poll(&mut Context::from_waker(Waker::noop())),
+                Poll::Ready(())
+            );
+        }
+
+        ran[0]
+    }
+
+    #[test]
+    fn first_runs_when_neither_changed() {
+        let select = PrioritySelect::new(MockSelect::new("first"), MockSelect::new("second"));
+
+        assert_eq!(run_once(&select), "first");
+    }
+
+    #[test]
+    fn second_runs_when_changed_last() {
+        let start = Instant::now();
+        let select = PrioritySelect::new(MockSelect::new("first"), MockSelect::new("second"));
+
+        select.first().change(start);
+        select.second().change(start + Duration::from_millis(10));
+
+        assert_eq!(run_once(&select), "second");
+        assert_eq!(
+            select.last_changed(),
+            Some(start + Duration::from_millis(10))
+        );
+    }
+
+    #[test]
+    fn first_runs_when_changed_last() {
+        let start = Instant::now();
+        let select = PrioritySelect::new(MockSelect::new("first"), MockSelect::new("second"));
+
+        select.second().change(start);
+        select.first().change(start + Duration::from_millis(10));
+
+        assert_eq!(run_once(&select), "first");
+    }
+
+    #[test]
+    fn first_wins_ties() {
+        let start = Instant::now();
+        let select = PrioritySelect::new(MockSelect::new("first"), MockSelect::new("second"));
+
+        select.first().change(start);
+        select.second().change(start);
+
+        assert_eq!(run_once(&select), "first");
+    }
+
+    #[test]
+    fn second_runs_when_only_it_changed() {
+        let select = PrioritySelect::new(MockSelect::new("first"), MockSelect::new("second"));
+
+        select.second().change(Instant::now());
+
+        assert_eq!(run_once(&select), "second");
+    }
+}
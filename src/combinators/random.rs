@@ -0,0 +1,170 @@
+use std::{borrow::Cow, cell::Cell};
+
+use crate::{Selector, SelectorStatus, simple::Route};
+
+/// A selector that runs a random route each time it is run.
+///
+/// This is useful for demos at events, where the robot can cycle through all of its strategies
+/// without anyone picking them. Random numbers come from a simple linear congruential generator,
+/// which is seeded from the Brain's high-resolution timer by [`RandomSelector::new`], or from a
+/// fixed seed by [`RandomSelector::seeded`] for reproducible runs.
+///
+/// The next route is picked ahead of time, so [`RandomSelector::current_index`] always returns
+/// the route that will run next.
+///
+/// # Examples
+///
+/// ```ignore
+/// robot
+///     .compete(RandomSelector::new([
+///         route!(Robot::route_1),
+///         route!(Robot::route_2),
+///     ]))
+///     .await;
+/// ```
+pub struct RandomSelector<R: 'static, const N: usize> {
+    routes: [Route<R>; N],
+    current: Cell<usize>,
+    state: Cell<u64>,
+    timed_out: Cell<bool>,
+}
+
+impl<R, const N: usize> RandomSelector<R, N> {
+    /// Creates a new random selector seeded from the Brain's high-resolution timer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `routes` is empty.
+    pub fn new(routes: [Route<R>; N]) -> Self {
+        // SAFETY: `vexSystemHighResTimeGet` has no preconditions.
+        let seed = unsafe { vex_sdk::vexSystemHighResTimeGet() };
+        Self::seeded(seed, routes)
+    }
+
+    /// Creates a new random selector with a fixed seed.
+    ///
+    /// Selectors created with the same seed and number of routes always pick routes in the same
+    /// order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `routes` is empty.
+    pub fn seeded(seed: u64, routes: [Route<R>; N]) -> Self {
+        const {
+            assert!(N > 0, "RandomSelector requires at least one route.");
+        }
+
+        let selector = Self {
+            routes,
+            current: Cell::new(0),
+            state: Cell::new(seed),
+            timed_out: Cell::new(false),
+        };
+        selector.advance();
+        selector
+    }
+
+    /// Returns the index of the route that will run next.
+    pub fn current_index(&self) -> usize {
+        self.current.get()
+    }
+
+    /// Returns the route that will run next.
+    pub fn current_route(&self) -> &Route<R> {
+        &self.routes[self.current.get()]
+    }
+
+    /// Returns the routes in this selector.
+    pub const fn routes(&self) -> &[Route<R>; N] {
+        &self.routes
+    }
+
+    /// Picks a new random route to run next.
+    fn advance(&self) {
+        // Constants from Knuth's MMIX.
+        let state = self
+            .state
+            .get()
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.state.set(state);
+
+        // The high bits of an LCG are much more random than the low bits.
+        self.current.set(((state >> 33) % N as u64) as usize);
+    }
+}
+
+impl<R, const N: usize> Selector<R> for RandomSelector<R, N> {
+    async fn run(&self, robot: &mut R) {
+        let route = self.current_route().clone();
+        self.advance();
+
+        let timings = route.run(robot).await;
+        self.timed_out.set(timings.timed_out);
+    }
+
+    fn status(&self) -> Option<&dyn SelectorStatus<R>> {
+        Some(self)
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        Some(self.current.get())
+    }
+
+    fn name_of(&self, index: usize) -> Option<Cow<'static, str>> {
+        self.routes.get(index).map(|route| route.name.clone())
+    }
+}
+
+impl<R, const N: usize> SelectorStatus<R> for RandomSelector<R, N> {
+    fn is_noop(&self) -> bool {
+        self.current_route().is_noop
+    }
+
+    fn timed_out(&self) -> bool {
+        self.timed_out.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RandomSelector;
+    use crate::simple::Route;
+
+    fn routes<const N: usize>() -> [Route<()>; N] {
+        std::array::from_fn(|_| Route::new("Route", |_| Box::pin(async {})))
+    }
+
+    #[test]
+    fn picks_routes_evenly() {
+        const PICKS: usize = 10_000;
+
+        let selector = RandomSelector::<(), 5>::seeded(0xC0FFEE, routes());
+        let mut counts = [0_usize; 5];
+
+        for _ in 0..PICKS {
+            counts[selector.current_index()] += 1;
+            selector.advance();
+        }
+
+        let expected = PICKS / counts.len();
+        for count in counts {
+            assert!(
+                count.abs_diff(expected) < expected / 10,
+                "Uneven route distribution: {counts:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn same_seed_picks_same_routes() {
+        let first = RandomSelector::<(), 4>::seeded(42, routes());
+        let second = RandomSelector::<(), 4>::seeded(42, routes());
+
+        for _ in 0..100 {
+            assert_eq!(first.current_index(), second.current_index());
+            first.advance();
+            second.advance();
+        }
+    }
+}
@@ -7,15 +7,90 @@
 //! [`Compete`]: vexide::competition::Compete
 //! [`CompeteExt`]: vexide::competition::CompeteExt
 
-use std::{future::Future, ops::ControlFlow, pin::Pin};
+use std::{
+    cell::Cell,
+    future::Future,
+    ops::ControlFlow,
+    pin::Pin,
+    time::{Duration, Instant},
+};
 
-use vexide::competition::CompetitionRuntime;
+/// Derives an implementation of [`SelectCompete`](trait@SelectCompete) using the default for
+/// every hook.
+///
+/// Routes may be listed with `#[autonomous_route(Robot::route)]` attributes, in which case an
+/// inherent `compete_with_routes(display)` method is also generated that runs the robot with a
+/// [`SimpleSelect`](crate::simple::SimpleSelect) over those routes.
+#[cfg(feature = "derive")]
+pub use autons_derive::SelectCompete;
+use vexide::{
+    competition::{self, CompetitionMode, CompetitionRuntime, CompetitionSystem},
+    time::sleep,
+};
+
+use crate::{Selector, status_of};
+
+thread_local! {
+    static CURRENT_PHASE: Cell<CompetitionPhase> = const { Cell::new(CompetitionPhase::Disconnected) };
+}
+
+/// The [`SelectCompete`] hook that is currently running.
+///
+/// This is returned by [`SelectCompete::competition_phase`]. Unlike vexide's [`CompetitionMode`],
+/// it also tells apart the hooks that run when the robot connects to or disconnects from a
+/// competition controller.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum CompetitionPhase {
+    /// The robot is in driver control, running [`SelectCompete::driver`].
+    Driver,
+
+    /// The robot is disabled, running [`SelectCompete::disabled`].
+    Disabled,
+
+    /// The robot is running the autonomous period, including the selected route and the hooks
+    /// around it.
+    Autonomous,
+
+    /// The robot just connected to a competition controller, running
+    /// [`SelectCompete::connected`] and [`SelectCompete::on_connect_info`].
+    Connected,
+
+    /// The robot is not connected to a competition controller, or just disconnected from one
+    /// and is running [`SelectCompete::disconnected`].
+    #[default]
+    Disconnected,
+}
+
+/// Information about the competition controller the robot connected to.
+///
+/// This is passed to [`SelectCompete::on_connect_info`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CompetitionInfo {
+    /// `true` if the robot is connected to a field controller, rather than a competition switch.
+    pub field_control: bool,
 
-use crate::Selector;
+    /// Whether the robot is set up for programming skills, as reported by
+    /// [`SelectCompete::is_skills`].
+    pub is_skills: bool,
+
+    /// The type of competition controller the robot is connected to, if known.
+    pub system: Option<CompetitionSystem>,
+
+    /// The competition mode at the time of connection.
+    pub mode: CompetitionMode,
+}
 
 /// A set of functions and routes to run when the competition is in a particular mode.
 #[allow(async_fn_in_trait)]
 pub trait SelectCompete: Sized {
+    /// How long to wait between calls to [`disabled_tick`].
+    ///
+    /// Defaults to 20 milliseconds.
+    ///
+    /// [`disabled_tick`]: SelectCompete::disabled_tick
+    const DISABLED_TICK_INTERVAL: Duration = Duration::from_millis(20);
+
     /// Runs when the robot is put into driver control mode.
     ///
     /// When in opcontrol mode, all device access is available including access to
@@ -34,13 +109,36 @@ pub trait SelectCompete: Sized {
     /// Robots may be placed into disabled mode at any point in the competition after
     /// connecting, but are typically disabled before the autonomous period, between
     /// autonomous and opcontrol periods, and following the opcontrol period of a match.
+    ///
+    /// Once this returns, [`disabled_tick`] is called repeatedly for the rest of the disabled
+    /// period.
+    ///
+    /// [`disabled_tick`]: SelectCompete::disabled_tick
     async fn disabled(&mut self) {}
 
+    /// Runs repeatedly while the robot is disabled, after [`disabled`] returns.
+    ///
+    /// Calls are spaced out by [`DISABLED_TICK_INTERVAL`], and stop as soon as the robot leaves
+    /// disabled mode. This is useful for polling sensors, updating a display, or blinking LEDs
+    /// while waiting for a match to start.
+    ///
+    /// [`disabled`]: SelectCompete::disabled
+    /// [`DISABLED_TICK_INTERVAL`]: SelectCompete::DISABLED_TICK_INTERVAL
+    async fn disabled_tick(&mut self) {}
+
     /// Runs when the robot becomes connected into a competition controller.
     ///
     /// See [`vexide::competition::CompetitionBuilder::on_connect`] for more information.
     async fn connected(&mut self) {}
 
+    /// Runs when the robot becomes connected into a competition controller, with information
+    /// about the connection.
+    ///
+    /// This runs immediately after [`connected`](SelectCompete::connected).
+    async fn on_connect_info(&mut self, info: CompetitionInfo) {
+        _ = info;
+    }
+
     /// Runs when the robot disconnects from a competition controller.
     ///
     /// <section class="warning">
@@ -55,11 +153,187 @@ pub trait SelectCompete: Sized {
     /// See [`vexide::competition::CompetitionBuilder::on_disconnect`] for more information.
     async fn disconnected(&mut self) {}
 
+    /// Runs at the start of the autonomous period to check that the robot's hardware is ready.
+    ///
+    /// This is useful for making sure that sensors a route relies on, such as an IMU, are
+    /// connected and calibrated. If this returns an error, the message is passed to the
+    /// selector's [`show_error`], and nothing else runs for the rest of the autonomous period:
+    /// neither the selected route and the hooks around it, nor [`skills`].
+    ///
+    /// Defaults to `Ok(())`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// async fn validate_hardware(&mut self) -> Result<(), &'static str> {
+    ///     if !self.imu.is_connected() {
+    ///         return Err("IMU is disconnected");
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`skills`]: SelectCompete::skills
+    /// [`show_error`]: crate::SelectorStatus::show_error
+    async fn validate_hardware(&mut self) -> Result<(), &'static str> {
+        Ok(())
+    }
+
     /// Runs immediately *before* the selected autonomous route.
     async fn before_route(&mut self) {}
 
     /// Runs immediately *after* the selected autonomous route.
+    ///
+    /// This runs even if the route was stopped for exceeding its [timeout], in which case
+    /// [`route_timed_out`] runs first.
+    ///
+    /// [timeout]: crate::simple::Route::timeout
+    /// [`route_timed_out`]: SelectCompete::route_timed_out
     async fn after_route(&mut self) {}
+
+    /// Runs immediately *before* [`driver`] each time the robot enters driver control.
+    ///
+    /// [`driver`]: SelectCompete::driver
+    async fn before_driver(&mut self) {}
+
+    /// Runs immediately *after* [`driver`] returns.
+    ///
+    /// This only runs if [`driver`] returns on its own. If the robot leaves driver control while
+    /// [`driver`] is still running, it is stopped and this is skipped.
+    ///
+    /// [`driver`]: SelectCompete::driver
+    async fn after_driver(&mut self) {}
+
+    /// Runs after the selected autonomous route with the wall-clock time it took to run, before
+    /// [`after_route`].
+    ///
+    /// The duration is measured from just before the route starts to just after it returns,
+    /// which is useful for logging route timings over serial or to an SD card for post-match
+    /// analysis.
+    ///
+    /// [`after_route`]: SelectCompete::after_route
+    async fn route_elapsed(&mut self, duration: Duration) {
+        _ = duration;
+    }
+
+    /// Runs at the start of the next competition phase if the autonomous route was stopped before
+    /// it finished.
+    ///
+    /// This happens when the robot leaves autonomous while the route is still running, such as
+    /// when the field switches to driver control early. The route's future is dropped wherever it
+    /// was last suspended, and neither [`after_route`] nor any code after the route's last
+    /// `.await` runs, so this is the place to put mechanisms back into a safe state, such as
+    /// stopping the drivetrain. It runs before the next phase's own hooks, such as [`disabled`]
+    /// or [`before_driver`].
+    ///
+    /// [`after_route`]: SelectCompete::after_route
+    /// [`disabled`]: SelectCompete::disabled
+    /// [`before_driver`]: SelectCompete::before_driver
+    async fn route_cancelled(&mut self) {}
+
+    /// Runs after the selected autonomous route was stopped for exceeding its [timeout], before
+    /// [`after_route`].
+    ///
+    /// This is useful for putting mechanisms back into a known state, since a route that times
+    /// out is stopped wherever it was last suspended.
+    ///
+    /// [timeout]: crate::simple::Route::timeout
+    /// [`after_route`]: SelectCompete::after_route
+    async fn route_timed_out(&mut self) {}
+
+    /// Runs after the selected autonomous route panicked, before [`after_route`].
+    ///
+    /// `route_index` is the selector's [index](Selector::selected_index) of the route that
+    /// panicked, or `None` if the selector doesn't index its routes. The panic is caught so that
+    /// the rest of the competition can go on, and the default implementation logs it over serial.
+    ///
+    /// <section class="warning">
+    ///
+    /// Panics are only caught in host builds, such as tests. Panics abort on the V5 brain, so
+    /// there this hook never runs and the program stops at the panic. On the robot, a route's
+    /// [timeout] is the watchdog that keeps a stuck route from taking the rest of the period.
+    ///
+    /// </section>
+    ///
+    /// [`after_route`]: SelectCompete::after_route
+    /// [timeout]: crate::simple::Route::timeout
+    async fn route_panicked(&mut self, route_index: Option<usize>) {
+        match route_index {
+            Some(index) => println!("[autons] route {index} panicked"),
+            None => println!("[autons] route panicked"),
+        }
+    }
+
+    /// Returns `true` if the selector should be [reset] to its default route after each
+    /// autonomous route, following [`after_route`].
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [reset]: Selector::reset
+    /// [`after_route`]: SelectCompete::after_route
+    fn reset_after_route(&self) -> bool {
+        false
+    }
+
+    /// Runs during the autonomous period in place of the selected route when [`is_skills`]
+    /// returns `true`.
+    ///
+    /// [`before_route`] and [`after_route`] are not called around this function.
+    ///
+    /// [`is_skills`]: SelectCompete::is_skills
+    /// [`before_route`]: SelectCompete::before_route
+    /// [`after_route`]: SelectCompete::after_route
+    async fn skills(&mut self) {}
+
+    /// Returns `true` if the robot is being run for programming skills rather than a match.
+    ///
+    /// VEXos does not report whether the robot is running a skills attempt, so this must be
+    /// determined by the program itself. Common approaches are checking a jumper or limit switch
+    /// on a three-wire port, or reading a flag set from a controller button while disabled.
+    ///
+    /// Defaults to `false`, meaning that [`skills`](SelectCompete::skills) never runs.
+    fn is_skills(&self) -> bool {
+        false
+    }
+
+    /// Returns which phase of the competition the robot is in.
+    ///
+    /// This can be called from any hook to find out which of them is running, which is useful
+    /// for helpers shared between several hooks.
+    fn competition_phase(&self) -> CompetitionPhase {
+        CURRENT_PHASE.get()
+    }
+}
+
+/// Polls a future to completion, returning `Err` with the panic payload if polling it panics.
+///
+/// Panics abort on the brain, so they are only caught in host builds such as tests.
+#[cfg(not(target_os = "vexos"))]
+async fn catch_unwind<F: Future>(future: F) -> std::thread::Result<F::Output> {
+    use std::{
+        future::poll_fn,
+        panic::{self, AssertUnwindSafe},
+        pin::pin,
+        task::Poll,
+    };
+
+    let mut future = pin!(future);
+
+    poll_fn(
+        |cx| match panic::catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(cx))) {
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(payload) => Poll::Ready(Err(payload)),
+        },
+    )
+    .await
+}
+
+/// Runs a future to completion. Panics abort on the brain, so there is nothing to catch.
+#[cfg(target_os = "vexos")]
+async fn catch_unwind<F: Future>(future: F) -> std::thread::Result<F::Output> {
+    Ok(future.await)
 }
 
 /// Internal shared state for [`SelectCompete`]'s competition runtime instance.
@@ -69,6 +343,17 @@ pub trait SelectCompete: Sized {
 pub struct SelectCompeteShared<R, S: Selector<R>> {
     robot: R,
     selector: S,
+    route_running: bool,
+}
+
+impl<R: SelectCompete, S: Selector<R>> SelectCompeteShared<R, S> {
+    /// Runs [`SelectCompete::route_cancelled`] if the last autonomous route was dropped before it
+    /// finished.
+    async fn finish_cancelled_route(&mut self) {
+        if std::mem::take(&mut self.route_running) {
+            self.robot.route_cancelled().await;
+        }
+    }
 }
 
 /// Extension methods for [`SelectCompete`].
@@ -101,36 +386,117 @@ pub trait SelectCompeteExt<S: Selector<Self>>: SelectCompete {
         CompetitionRuntime::builder(SelectCompeteShared {
             robot: self,
             selector,
+            route_running: false,
         })
         .on_connect(|s| {
             Box::pin(async {
+                CURRENT_PHASE.set(CompetitionPhase::Connected);
+                s.finish_cancelled_route().await;
                 s.robot.connected().await;
+
+                let status = competition::status();
+                let info = CompetitionInfo {
+                    field_control: status.system() == Some(CompetitionSystem::FieldControl),
+                    is_skills: s.robot.is_skills(),
+                    system: status.system(),
+                    mode: status.mode(),
+                };
+                s.robot.on_connect_info(info).await;
+
                 ControlFlow::<!>::Continue(())
             })
         })
         .on_disconnect(|s| {
             Box::pin(async {
+                CURRENT_PHASE.set(CompetitionPhase::Disconnected);
+                s.finish_cancelled_route().await;
+                status_of(&s.selector).redraw();
                 s.robot.disconnected().await;
                 ControlFlow::Continue(())
             })
         })
         .while_disabled(|s| {
             Box::pin(async {
+                CURRENT_PHASE.set(CompetitionPhase::Disabled);
+                s.finish_cancelled_route().await;
+                status_of(&s.selector).redraw();
                 s.robot.disabled().await;
-                ControlFlow::Continue(())
+
+                // The runtime drops this future once the robot is no longer disabled.
+                loop {
+                    s.robot.disabled_tick().await;
+                    sleep(Self::DISABLED_TICK_INTERVAL).await;
+                }
             })
         })
         .while_autonomous(|s| {
             Box::pin(async {
+                CURRENT_PHASE.set(CompetitionPhase::Autonomous);
+                s.finish_cancelled_route().await;
+
+                if let Err(message) = s.robot.validate_hardware().await {
+                    status_of(&s.selector).show_error(message);
+                    return ControlFlow::Continue(());
+                }
+
+                if s.robot.is_skills() {
+                    s.robot.skills().await;
+                    return ControlFlow::Continue(());
+                }
+
+                s.route_running = true;
                 s.robot.before_route().await;
-                s.selector.run(&mut s.robot).await;
+
+                let is_noop = status_of(&s.selector).is_noop();
+
+                if let Some(telemetry) = s.selector.telemetry()
+                    && !is_noop
+                {
+                    telemetry.route_started();
+                }
+
+                let route_index = s.selector.selected_index();
+                let start = Instant::now();
+                let result = catch_unwind(s.selector.run(&mut s.robot)).await;
+                let elapsed = start.elapsed();
+
+                if let Some(telemetry) = s.selector.telemetry() {
+                    if is_noop {
+                        telemetry.noop_route_ran();
+                    } else {
+                        telemetry.route_ended(elapsed);
+                    }
+                }
+
+                s.robot.route_elapsed(elapsed).await;
+
+                if status_of(&s.selector).timed_out() {
+                    s.robot.route_timed_out().await;
+                }
+
+                if result.is_err() {
+                    s.robot.route_panicked(route_index).await;
+                }
+
                 s.robot.after_route().await;
+                status_of(&s.selector).redraw();
+
+                if s.robot.reset_after_route() {
+                    s.selector.reset();
+                }
+
+                s.route_running = false;
+
                 ControlFlow::Continue(())
             })
         })
         .while_driving(|s| {
             Box::pin(async {
+                CURRENT_PHASE.set(CompetitionPhase::Driver);
+                s.finish_cancelled_route().await;
+                s.robot.before_driver().await;
                 s.robot.driver().await;
+                s.robot.after_driver().await;
                 ControlFlow::Continue(())
             })
         })
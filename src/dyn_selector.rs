@@ -0,0 +1,102 @@
+use std::{borrow::Cow, future::Future, pin::Pin, time::Instant};
+
+use crate::{Selector, SelectorStatus, SelectorTelemetry};
+
+/// An object-safe version of [`Selector`], implemented for every selector.
+trait DynSelectorInner<R> {
+    fn run_boxed<'s>(&'s self, robot: &'s mut R) -> Pin<Box<dyn Future<Output = ()> + 's>>;
+    fn telemetry(&self) -> Option<&dyn SelectorTelemetry>;
+    fn last_changed(&self) -> Option<Instant>;
+    fn reset(&mut self);
+    fn status(&self) -> Option<&dyn SelectorStatus<R>>;
+    fn selected_index(&self) -> Option<usize>;
+    fn name_of(&self, index: usize) -> Option<Cow<'static, str>>;
+}
+
+impl<R, S: Selector<R>> DynSelectorInner<R> for S {
+    fn run_boxed<'s>(&'s self, robot: &'s mut R) -> Pin<Box<dyn Future<Output = ()> + 's>> {
+        Box::pin(self.run(robot))
+    }
+
+    fn telemetry(&self) -> Option<&dyn SelectorTelemetry> {
+        Selector::telemetry(self)
+    }
+
+    fn last_changed(&self) -> Option<Instant> {
+        Selector::last_changed(self)
+    }
+
+    fn reset(&mut self) {
+        Selector::reset(self);
+    }
+
+    fn status(&self) -> Option<&dyn SelectorStatus<R>> {
+        Selector::status(self)
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        Selector::selected_index(self)
+    }
+
+    fn name_of(&self, index: usize) -> Option<Cow<'static, str>> {
+        Selector::name_of(self, index)
+    }
+}
+
+/// A type-erased [`Selector`].
+///
+/// [`Selector`] can't be used as a trait object, since its [`run`](Selector::run) method returns
+/// `impl Future`. `DynSelector` boxes both the selector and the futures it returns, allowing
+/// selectors of different types to be stored together, such as in a `Vec<DynSelector<Robot>>`,
+/// or chosen at runtime. A `DynSelector` can be created with [`DynSelector::new`] or
+/// [`Selector::boxed`].
+///
+/// # Examples
+///
+/// ```ignore
+/// let selector = if has_rotation_sensor {
+///     RotarySelector::new(peripherals.port_1, routes).boxed()
+/// } else {
+///     SimpleSelect::new(peripherals.display, routes).boxed()
+/// };
+///
+/// robot.compete(selector).await;
+/// ```
+pub struct DynSelector<R>(Box<dyn DynSelectorInner<R>>);
+
+impl<R> DynSelector<R> {
+    /// Erases the type of a selector.
+    pub fn new<S: Selector<R> + 'static>(selector: S) -> Self {
+        Self(Box::new(selector))
+    }
+}
+
+impl<R> Selector<R> for DynSelector<R> {
+    async fn run(&self, robot: &mut R) {
+        self.0.run_boxed(robot).await;
+    }
+
+    fn telemetry(&self) -> Option<&dyn SelectorTelemetry> {
+        self.0.telemetry()
+    }
+
+    fn status(&self) -> Option<&dyn SelectorStatus<R>> {
+        self.0.status()
+    }
+
+    fn last_changed(&self) -> Option<Instant> {
+        self.0.last_changed()
+    }
+
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        self.0.selected_index()
+    }
+
+    fn name_of(&self, index: usize) -> Option<Cow<'static, str>> {
+        self.0.name_of(index)
+    }
+}
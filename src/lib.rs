@@ -85,7 +85,9 @@
 
 #![feature(never_type)]
 
-use std::future::Future;
+use std::{borrow::Cow, future::Future, time::Instant};
+
+pub mod cancel;
 
 #[cfg(feature = "simple")]
 pub mod simple;
@@ -93,15 +95,203 @@ pub mod simple;
 #[cfg(feature = "compete")]
 pub mod compete;
 
+#[cfg(feature = "combinators")]
+pub mod combinators;
+
+#[cfg(feature = "rotary-select")]
+pub mod rotary_select;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(all(feature = "simple", any(test, feature = "test-utils")))]
+pub mod test_select;
+
+mod color_ext;
+mod dyn_selector;
+mod mapped;
+mod telemetry;
+
+pub use color_ext::ColorExt;
+pub use dyn_selector::DynSelector;
+pub use mapped::MappedSelector;
+pub use telemetry::{RouteTimings, SelectorTelemetry, SerialTelemetry};
+
 /// A structure that picks an autonomous route to run.
 pub trait Selector<R> {
     fn run(&self, robot: &mut R) -> impl Future<Output = ()>;
+
+    /// Returns the [telemetry receiver] attached to this selector, if any.
+    ///
+    /// [telemetry receiver]: SelectorTelemetry
+    fn telemetry(&self) -> Option<&dyn SelectorTelemetry> {
+        None
+    }
+
+    /// Returns the [status] of this selector, if it reports one.
+    ///
+    /// Selectors that return `None` are treated as if every [`SelectorStatus`] method used its
+    /// default implementation.
+    ///
+    /// [status]: SelectorStatus
+    fn status(&self) -> Option<&dyn SelectorStatus<R>> {
+        None
+    }
+
+    /// Returns the time at which the selection was last changed, if it has changed at all.
+    ///
+    /// Selectors that allow the selection to be changed should report it here so that
+    /// combinators such as [`PrioritySelect`] can tell which selector was used most recently.
+    ///
+    /// [`PrioritySelect`]: crate::combinators::PrioritySelect
+    fn last_changed(&self) -> Option<Instant> {
+        None
+    }
+
+    /// Returns the selection to the selector's default route.
+    ///
+    /// Calling this more than once must have the same effect as calling it once. Selectors
+    /// without a notion of a default selection can leave this as the default implementation,
+    /// which does nothing.
+    fn reset(&mut self) {}
+
+    /// Returns the index of the route that [`run`](Selector::run) would run next, if the selector
+    /// has indexed routes.
+    ///
+    /// Selectors that don't have indexed routes can leave this as the default implementation,
+    /// which always returns `None`.
+    fn selected_index(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the name of the route at `index`, or `None` if there is no such route.
+    ///
+    /// Selectors that don't have named routes can leave this as the default implementation,
+    /// which always returns `None`.
+    fn name_of(&self, index: usize) -> Option<Cow<'static, str>> {
+        _ = index;
+        None
+    }
+
+    /// Adapts this selector to run on a larger robot type by projecting out the part of the
+    /// robot its routes operate on.
+    ///
+    /// This allows routes to be written against an individual subsystem (such as a drivetrain)
+    /// and shared between robots with different top-level structs.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// // Routes that only need the drivetrain.
+    /// let selector = SimpleSelect::new(
+    ///     peripherals.display,
+    ///     [route!("Drive Forward", Drivetrain::drive_forward)],
+    /// );
+    ///
+    /// robot
+    ///     .compete(selector.map_robot(|robot: &mut Robot| &mut robot.drivetrain))
+    ///     .await;
+    /// ```
+    fn map_robot<T, F>(self, projection: F) -> MappedSelector<Self, F, R>
+    where
+        Self: Sized,
+        F: Fn(&mut T) -> &mut R,
+    {
+        MappedSelector::new(self, projection)
+    }
+
+    /// Erases the type of this selector, so that it can be stored alongside selectors of other
+    /// types.
+    ///
+    /// See [`DynSelector`] for more information.
+    fn boxed(self) -> DynSelector<R>
+    where
+        Self: Sized + 'static,
+    {
+        DynSelector::new(self)
+    }
 }
 
+/// Optional [`Selector`] behavior that [`SelectCompete`] uses while running it.
+///
+/// These only make sense for some selectors, so they are kept out of [`Selector`] itself.
+/// Selectors opt in by returning themselves from [`Selector::status`]:
+///
+/// ```ignore
+/// impl Selector<Robot> for MySelector {
+///     // ...
+///
+///     fn status(&self) -> Option<&dyn SelectorStatus<Robot>> {
+///         Some(self)
+///     }
+/// }
+///
+/// impl SelectorStatus<Robot> for MySelector {
+///     fn is_noop(&self) -> bool {
+///         self.selected_route().is_noop
+///     }
+/// }
+/// ```
+///
+/// [`SelectCompete`]: crate::compete::SelectCompete
+pub trait SelectorStatus<R> {
+    /// Returns `true` if the selected route is a no-op that does nothing when run.
+    ///
+    /// This is used to report no-op routes separately from real routes through
+    /// [`SelectorTelemetry::noop_route_ran`].
+    fn is_noop(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if the last route run by this selector was stopped because it exceeded its
+    /// time limit.
+    ///
+    /// Selectors without time limits always return `false`.
+    fn timed_out(&self) -> bool {
+        false
+    }
+
+    /// Repaints the selector's interface from its current state.
+    ///
+    /// This is called by [`SelectCompete`] when the autonomous and driver control periods end,
+    /// to restore the interface after routes or driver code draw over it. Selectors that don't
+    /// draw anything can leave this as the default implementation, which does nothing.
+    ///
+    /// [`SelectCompete`]: crate::compete::SelectCompete
+    fn redraw(&self) {}
+
+    /// Reports an error that stopped the selected route from running.
+    ///
+    /// This is called by [`SelectCompete`] when [`validate_hardware`] fails. Selectors that own
+    /// the display can override this to show the error until they are next
+    /// [redrawn](SelectorStatus::redraw). The default implementation only prints it over serial.
+    ///
+    /// [`SelectCompete`]: crate::compete::SelectCompete
+    /// [`validate_hardware`]: crate::compete::SelectCompete::validate_hardware
+    fn show_error(&self, message: &str) {
+        println!("[autons] hardware check failed: {message}");
+    }
+}
+
+/// The [`SelectorStatus`] of selectors that don't report one.
+struct DefaultStatus;
+
+impl<R> SelectorStatus<R> for DefaultStatus {}
+
+/// Returns the [status](Selector::status) of `selector`, falling back to the default behavior.
+pub(crate) fn status_of<R>(selector: &(impl Selector<R> + ?Sized)) -> &dyn SelectorStatus<R> {
+    selector.status().unwrap_or(&DefaultStatus)
+}
+
+// Ensures that the examples in the README continue to compile.
+#[cfg(doctest)]
+#[doc = include_str!("../README.md")]
+struct ReadmeDoctests;
+
 /// Commonly used types and traits.
 ///
 /// This module is meant to be glob imported.
 pub mod prelude {
     #[cfg(feature = "compete")]
-    pub use crate::compete::{SelectCompete, SelectCompeteExt};
+    pub use crate::compete::{CompetitionPhase, SelectCompete, SelectCompeteExt};
 }
@@ -0,0 +1,90 @@
+use std::{borrow::Cow, marker::PhantomData, time::Instant};
+
+use crate::{Selector, SelectorStatus, SelectorTelemetry, status_of};
+
+/// A selector that runs routes written for part of a robot.
+///
+/// This is created by the [`Selector::map_robot`] method. See its documentation for more
+/// information.
+pub struct MappedSelector<S, F, R> {
+    selector: S,
+    projection: F,
+    _robot: PhantomData<fn(&mut R)>,
+}
+
+impl<S, F, R> MappedSelector<S, F, R> {
+    pub(crate) const fn new(selector: S, projection: F) -> Self {
+        Self {
+            selector,
+            projection,
+            _robot: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the wrapped selector.
+    pub const fn inner(&self) -> &S {
+        &self.selector
+    }
+
+    /// Returns a mutable reference to the wrapped selector.
+    pub const fn inner_mut(&mut self) -> &mut S {
+        &mut self.selector
+    }
+
+    /// Unwraps this adapter, returning the underlying selector.
+    pub fn into_inner(self) -> S {
+        self.selector
+    }
+}
+
+impl<T, R, S, F> Selector<T> for MappedSelector<S, F, R>
+where
+    S: Selector<R>,
+    F: Fn(&mut T) -> &mut R,
+{
+    async fn run(&self, robot: &mut T) {
+        self.selector.run((self.projection)(robot)).await;
+    }
+
+    fn telemetry(&self) -> Option<&dyn SelectorTelemetry> {
+        self.selector.telemetry()
+    }
+
+    fn status(&self) -> Option<&dyn SelectorStatus<T>> {
+        Some(self)
+    }
+
+    fn last_changed(&self) -> Option<Instant> {
+        self.selector.last_changed()
+    }
+
+    fn reset(&mut self) {
+        self.selector.reset();
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        self.selector.selected_index()
+    }
+
+    fn name_of(&self, index: usize) -> Option<Cow<'static, str>> {
+        self.selector.name_of(index)
+    }
+}
+
+impl<T, R, S: Selector<R>, F> SelectorStatus<T> for MappedSelector<S, F, R> {
+    fn is_noop(&self) -> bool {
+        status_of(&self.selector).is_noop()
+    }
+
+    fn timed_out(&self) -> bool {
+        status_of(&self.selector).timed_out()
+    }
+
+    fn redraw(&self) {
+        status_of(&self.selector).redraw();
+    }
+
+    fn show_error(&self, message: &str) {
+        status_of(&self.selector).show_error(message);
+    }
+}
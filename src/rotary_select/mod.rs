@@ -0,0 +1,173 @@
+//! Autonomous route selection using a V5 rotation sensor.
+//!
+//! [`RotarySelector`] picks between routes based on the position of a [`RotationSensor`], allowing
+//! a physical knob to be used in place of the touchscreen. This is more reliable than a touchscreen
+//! in high-vibration environments.
+//!
+//! The selector does not draw anything to the display on its own. Instead, it is meant to be
+//! combined with a custom HUD that reads [`RotarySelector::selected_route`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use autons::{
+//!     prelude::*,
+//!     rotary_select::RotarySelector,
+//!     simple::route,
+//! };
+//! use vexide::prelude::*;
+//!
+//! struct Robot {}
+//!
+//! impl Robot {
+//!     async fn route_1(&mut self) {}
+//!     async fn route_2(&mut self) {}
+//! }
+//!
+//! impl SelectCompete for Robot {}
+//!
+//! #[vexide::main]
+//! async fn main(peripherals: Peripherals) {
+//!     let robot = Robot {};
+//!     let knob = RotationSensor::new(peripherals.port_1, Direction::Forward);
+//!
+//!     robot
+//!         .compete(RotarySelector::new(
+//!             knob,
+//!             [
+//!                 route!("Route 1", Robot::route_1),
+//!                 route!("Route 2", Robot::route_2),
+//!             ],
+//!         ))
+//!         .await;
+//! }
+//! ```
+
+use std::{borrow::Cow, cell::Cell};
+
+use vexide::smart::rotation::RotationSensor;
+
+use crate::{Selector, SelectorStatus, simple::Route};
+
+/// Autonomous route selector controlled by a rotation sensor.
+///
+/// The selected route is determined by dividing the sensor's position into steps of
+/// [`degrees_per_step`] degrees, wrapping around after the last route. For example, with the
+/// default of 30° per step and three routes, turning the sensor between 0° and 30° selects the
+/// first route, between 30° and 60° selects the second, and so on.
+///
+/// Routes that aren't [available](Route::is_available) are skipped, so each step moves to the
+/// next available route. If no route is available, nothing is selected and running the selector
+/// does nothing.
+///
+/// If the sensor cannot be read (for example, because it was unplugged), the first available
+/// route is selected.
+///
+/// [`degrees_per_step`]: RotarySelector::with_degrees_per_step
+pub struct RotarySelector<R: 'static, const N: usize> {
+    sensor: RotationSensor,
+    routes: [Route<R>; N],
+    degrees_per_step: f64,
+    timed_out: Cell<bool>,
+}
+
+impl<R, const N: usize> RotarySelector<R, N> {
+    /// The default number of degrees the sensor must turn to move to the next route.
+    pub const DEFAULT_DEGREES_PER_STEP: f64 = 30.0;
+
+    /// Creates a new selector from a [`RotationSensor`] and array of routes.
+    pub const fn new(sensor: RotationSensor, routes: [Route<R>; N]) -> Self {
+        const {
+            assert!(N > 0, "RotarySelector requires at least one route.");
+        }
+
+        Self {
+            sensor,
+            routes,
+            degrees_per_step: Self::DEFAULT_DEGREES_PER_STEP,
+            timed_out: Cell::new(false),
+        }
+    }
+
+    /// Sets the number of degrees the sensor must turn to move to the next route.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degrees_per_step` is not a positive number.
+    pub fn with_degrees_per_step(mut self, degrees_per_step: f64) -> Self {
+        assert!(
+            degrees_per_step > 0.0,
+            "Degrees per step must be a positive number."
+        );
+
+        self.degrees_per_step = degrees_per_step;
+        self
+    }
+
+    /// Returns the currently selected route, or `None` if no route is available.
+    ///
+    /// The index of the selected route is given by [`Selector::selected_index`].
+    pub fn selected_route(&self) -> Option<&Route<R>> {
+        self.selection().map(|index| &self.routes[index])
+    }
+
+    /// Returns the index of the available route the sensor is pointing at.
+    fn selection(&self) -> Option<usize> {
+        let available = self
+            .routes
+            .iter()
+            .filter(|route| route.is_available())
+            .count();
+        if available == 0 {
+            return None;
+        }
+
+        let step = self.sensor.position().map_or(0, |position| {
+            ((position.as_degrees() / self.degrees_per_step).floor() as i64)
+                .rem_euclid(available as i64) as usize
+        });
+
+        (0..N)
+            .filter(|&index| self.routes[index].is_available())
+            .nth(step)
+    }
+
+    /// Returns the routes this selector picks between.
+    pub const fn routes(&self) -> &[Route<R>; N] {
+        &self.routes
+    }
+}
+
+impl<R, const N: usize> Selector<R> for RotarySelector<R, N> {
+    async fn run(&self, robot: &mut R) {
+        let Some(route) = self.selected_route() else {
+            self.timed_out.set(false);
+            return;
+        };
+
+        let timings = route.run(robot).await;
+        self.timed_out.set(timings.timed_out);
+    }
+
+    fn status(&self) -> Option<&dyn SelectorStatus<R>> {
+        Some(self)
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        self.selection()
+    }
+
+    fn name_of(&self, index: usize) -> Option<Cow<'static, str>> {
+        self.routes.get(index).map(|route| route.name.clone())
+    }
+}
+
+impl<R, const N: usize> SelectorStatus<R> for RotarySelector<R, N> {
+    fn is_noop(&self) -> bool {
+        self.selected_route().is_some_and(|route| route.is_noop)
+    }
+
+    fn timed_out(&self) -> bool {
+        self.timed_out.get()
+    }
+}
@@ -0,0 +1,45 @@
+use vexide::color::Color;
+
+/// The alliance or game mode a [`Route`] is intended for.
+///
+/// [`SimpleSelect`] tints the cells of red and blue alliance routes to make them easier to tell
+/// apart. See [`Route::mirrored_pair`] for creating routes for both alliances at once.
+///
+/// [`Route`]: super::Route
+/// [`Route::mirrored_pair`]: super::Route::mirrored_pair
+/// [`SimpleSelect`]: super::SimpleSelect
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Alliance {
+    /// The red alliance.
+    Red,
+
+    /// The blue alliance.
+    Blue,
+
+    /// A programming skills run.
+    Skills,
+
+    /// Not specific to any alliance.
+    #[default]
+    None,
+}
+
+impl Alliance {
+    /// Returns the color used to tint routes for this alliance, if any.
+    pub const fn tint(self) -> Option<Color> {
+        match self {
+            Self::Red => Some(Color::new(200, 40, 40)),
+            Self::Blue => Some(Color::new(40, 90, 200)),
+            Self::Skills | Self::None => None,
+        }
+    }
+
+    /// Negates `value` on the blue alliance, for routes that run flipped across the field.
+    ///
+    /// This is handy for turn angles and lateral offsets in [mirrored pairs] of routes.
+    ///
+    /// [mirrored pairs]: super::Route::mirrored_pair
+    pub fn mirror(self, value: f64) -> f64 {
+        if self == Self::Blue { -value } else { value }
+    }
+}
@@ -0,0 +1,118 @@
+use std::{borrow::Cow, time::Instant};
+
+use vexide::display::Display;
+
+use super::{Alliance, Route, SimpleSelect};
+use crate::{Selector, SelectorStatus, SelectorTelemetry};
+
+/// A selector that only shows the routes for one alliance at a time.
+///
+/// `AllianceSelector` draws a single [`SimpleSelect`] that shows either the red or blue alliance
+/// routes, and only runs routes from the one matching its current alliance. The alliance can be
+/// changed at any time with [`AllianceSelector::set_alliance`], which is useful when it isn't
+/// announced until shortly before a match.
+///
+/// Any alliance other than [`Alliance::Blue`] shows the red routes.
+///
+/// # Examples
+///
+/// ```ignore
+/// let selector = AllianceSelector::new(
+///     peripherals.display,
+///     [route!("Left", Robot::red_left), route!("Right", Robot::red_right)],
+///     [route!("Left", Robot::blue_left), route!("Right", Robot::blue_right)],
+///     Alliance::Red,
+/// );
+/// ```
+pub struct AllianceSelector<R: 'static, const N: usize> {
+    selector: SimpleSelect<R, N>,
+    /// Routes of the alliance that isn't shown, along with their selection.
+    inactive_routes: [Route<R>; N],
+    inactive_selection: usize,
+    alliance: Alliance,
+}
+
+impl<R, const N: usize> AllianceSelector<R, N> {
+    /// Creates a new selector from a [`Display`] peripheral and arrays of red and blue alliance
+    /// routes, initially showing the routes for `alliance`.
+    pub fn new(
+        display: Display,
+        red_routes: [Route<R>; N],
+        blue_routes: [Route<R>; N],
+        alliance: Alliance,
+    ) -> Self {
+        let mut blue_routes = blue_routes;
+        SimpleSelect::prepare_routes(&mut blue_routes);
+
+        let mut selector = Self {
+            selector: SimpleSelect::new(display, red_routes),
+            inactive_selection: SimpleSelect::<R, N>::default_index(&blue_routes).unwrap_or(0),
+            inactive_routes: blue_routes,
+            alliance: Alliance::Red,
+        };
+        selector.set_alliance(alliance);
+        selector
+    }
+
+    /// Returns the alliance whose routes are currently shown.
+    pub const fn alliance(&self) -> Alliance {
+        self.alliance
+    }
+
+    /// Changes which alliance's routes are shown and run.
+    ///
+    /// Each alliance keeps its own selection, so switching back and forth doesn't lose it.
+    pub fn set_alliance(&mut self, alliance: Alliance) {
+        let was_blue = self.alliance == Alliance::Blue;
+        self.alliance = alliance;
+
+        if was_blue != (alliance == Alliance::Blue) {
+            self.selector
+                .swap_routes(&mut self.inactive_routes, &mut self.inactive_selection);
+        }
+    }
+
+    /// Returns the index of the selected route for the current alliance.
+    pub fn selected_index(&self) -> usize {
+        self.selector.selected_index()
+    }
+
+    /// Returns a reference to the selector showing the current alliance's routes.
+    pub const fn selector(&self) -> &SimpleSelect<R, N> {
+        &self.selector
+    }
+}
+
+impl<R, const N: usize> Selector<R> for AllianceSelector<R, N> {
+    async fn run(&self, robot: &mut R) {
+        self.selector.run(robot).await;
+    }
+
+    fn telemetry(&self) -> Option<&dyn SelectorTelemetry> {
+        self.selector.telemetry()
+    }
+
+    fn status(&self) -> Option<&dyn SelectorStatus<R>> {
+        self.selector.status()
+    }
+
+    fn last_changed(&self) -> Option<Instant> {
+        self.selector.last_changed()
+    }
+
+    fn reset(&mut self) {
+        self.selector.reset();
+
+        if let Some(default) = SimpleSelect::<R, N>::default_index(&self.inactive_routes) {
+            self.inactive_selection = default;
+        }
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        Some(self.selector.selected_index())
+    }
+
+    fn name_of(&self, index: usize) -> Option<Cow<'static, str>> {
+        self.selector.name_of(index)
+    }
+}
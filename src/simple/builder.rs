@@ -0,0 +1,438 @@
+use std::{path::PathBuf, rc::Rc, time::Duration};
+
+#[cfg(feature = "debug-logging")]
+use vexide::display::TouchState;
+use vexide::{controller::Controller, display::Display};
+
+use super::{
+    AnimatedTheme, Route, SimpleSelect, SimpleSelectLayout, SimpleSelectTheme, THEME_DARK,
+    Thumbnail,
+};
+use crate::SelectorTelemetry;
+
+/// Callback that receives raw touch coordinates and states.
+///
+/// See [`SimpleSelectBuilder::log_touches`].
+#[cfg(feature = "debug-logging")]
+pub type TouchLogger = Box<dyn Fn(i16, i16, TouchState)>;
+
+/// Builder for configuring a [`SimpleSelect`].
+///
+/// This is the preferred way of constructing a [`SimpleSelect`] with anything other than the
+/// default settings. A builder can be obtained through [`SimpleSelect::builder`].
+///
+/// # Examples
+///
+/// ```ignore
+/// let selector = SimpleSelect::builder([
+///     route!("Route 1", Robot::route_1),
+///     route!("Route 2", Robot::route_2),
+/// ])
+/// .theme(my_theme)
+/// .controller(peripherals.primary_controller)
+/// .persist_to("autons.txt")
+/// .build(peripherals.display);
+/// ```
+pub struct SimpleSelectBuilder<R: 'static, const N: usize> {
+    pub(crate) routes: [Route<R>; N],
+    pub(crate) theme: SimpleSelectTheme,
+    pub(crate) transition: Duration,
+    pub(crate) layout: SimpleSelectLayout,
+    pub(crate) grid: [usize; 2],
+    pub(crate) header: Option<&'static str>,
+    pub(crate) show_battery: bool,
+    pub(crate) show_route_count: bool,
+    pub(crate) show_competition_status: bool,
+    pub(crate) controller: Option<Controller>,
+    pub(crate) controller_line: Option<u8>,
+    pub(crate) on_selection_change: Option<Box<dyn FnMut(usize)>>,
+    pub(crate) persist_to: Option<PathBuf>,
+    pub(crate) mirror_to_serial: bool,
+    pub(crate) telemetry: Option<Rc<dyn SelectorTelemetry>>,
+    pub(crate) running_view: bool,
+    pub(crate) require_confirmation: bool,
+    pub(crate) confirmation_modal: bool,
+    pub(crate) confirmation_timeout: Duration,
+    pub(crate) long_press_threshold: Duration,
+    pub(crate) searchable: bool,
+    pub(crate) group_by_category: bool,
+    pub(crate) startup_animation: bool,
+    pub(crate) double_buffered: bool,
+    pub(crate) background_image: Option<Thumbnail>,
+    #[cfg(feature = "debug-logging")]
+    pub(crate) touch_logger: Option<TouchLogger>,
+}
+
+impl<R, const N: usize> SimpleSelectBuilder<R, N> {
+    /// Creates a new builder from an array of routes with the default settings.
+    pub fn new(routes: [Route<R>; N]) -> Self {
+        Self {
+            routes,
+            theme: THEME_DARK,
+            transition: Duration::ZERO,
+            layout: SimpleSelectLayout::default(),
+            grid: [6, 2],
+            header: None,
+            show_battery: false,
+            show_route_count: false,
+            show_competition_status: false,
+            controller: None,
+            controller_line: None,
+            on_selection_change: None,
+            persist_to: None,
+            mirror_to_serial: false,
+            telemetry: None,
+            running_view: true,
+            require_confirmation: false,
+            confirmation_modal: false,
+            confirmation_timeout: Duration::from_millis(1500),
+            long_press_threshold: Duration::from_millis(800),
+            searchable: false,
+            group_by_category: false,
+            startup_animation: false,
+            double_buffered: false,
+            background_image: None,
+            #[cfg(feature = "debug-logging")]
+            touch_logger: None,
+        }
+    }
+
+    /// Sets the [color theme] used by the selector.
+    ///
+    /// [color theme]: SimpleSelectTheme
+    pub fn theme(mut self, theme: SimpleSelectTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets an [animated color theme] used by the selector.
+    ///
+    /// [animated color theme]: AnimatedTheme
+    pub fn animated_theme(mut self, theme: AnimatedTheme) -> Self {
+        self.transition = theme.transition();
+        self.theme = theme.inner;
+        self
+    }
+
+    /// Sets how route entries are arranged on the display.
+    pub fn layout(mut self, layout: SimpleSelectLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the number of rows and columns in the [grid layout], which share the display evenly.
+    ///
+    /// Fewer, larger cells are easier to hit, such as a 2x2 grid for four routes. Routes fill
+    /// each column from top to bottom before moving on to the next. Defaults to 6 rows and 2
+    /// columns.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let selector = SimpleSelectBuilder::new(routes).grid::<2, 2>().build(peripherals.display);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if the grid has fewer cells than there are routes, or more rows or
+    /// columns than the display has pixels. [Building](SimpleSelectBuilder::build) the selector
+    /// panics if a header or footer leaves less than one pixel of height for each row.
+    ///
+    /// [grid layout]: SimpleSelectLayout::Grid
+    pub fn grid<const ROWS: usize, const COLUMNS: usize>(mut self) -> Self {
+        const {
+            assert!(
+                ROWS * COLUMNS >= N,
+                "SimpleSelect grid must have a cell for every route."
+            );
+            assert!(
+                ROWS > 0 && ROWS <= Display::VERTICAL_RESOLUTION as usize,
+                "SimpleSelect grid rows must be between one and the display height in pixels."
+            );
+            assert!(
+                COLUMNS > 0 && COLUMNS <= Display::HORIZONTAL_RESOLUTION as usize,
+                "SimpleSelect grid columns must be between one and the display width in pixels."
+            );
+        }
+
+        self.grid = [ROWS, COLUMNS];
+        self
+    }
+
+    /// Shows a header bar with the given text above the route list.
+    ///
+    /// This is useful for displaying information such as a team number, event name, or whether
+    /// the robot is set up for a match or skills run. The header takes up the top 20 pixels of
+    /// the display, and routes are shrunk to fit in the remaining space.
+    pub fn header(mut self, text: &'static str) -> Self {
+        self.header = Some(text);
+        self
+    }
+
+    /// Shows the robot's battery level in the bottom-right corner of the display.
+    ///
+    /// The indicator shows the battery's voltage and remaining capacity next to a battery icon.
+    /// It is refreshed every second, and the icon is colored green above 50%, yellow from 20%
+    /// to 50%, and red below 20%. It takes up the bottom 20 pixels of the display, and routes are
+    /// shrunk to fit in the remaining space. Disabled by default.
+    pub fn show_battery(mut self, show_battery: bool) -> Self {
+        self.show_battery = show_battery;
+        self
+    }
+
+    /// Shows how many routes can currently be selected out of the total in the bottom-left
+    /// corner of the display, such as "3 / 8 routes".
+    ///
+    /// Routes that are [disabled], don't meet their precondition, or are hidden by a filter or
+    /// search aren't counted as available. The counter is drawn in a faded text color, and is
+    /// updated whenever the page changes or the whole selector is redrawn. Like the
+    /// [battery indicator], it takes up the bottom 20 pixels of the display, and routes are shrunk
+    /// to fit in the remaining space. Disabled by default.
+    ///
+    /// [disabled]: SimpleSelect::set_route_enabled
+    /// [battery indicator]: SimpleSelectBuilder::show_battery
+    pub fn show_route_count(mut self, show_route_count: bool) -> Self {
+        self.show_route_count = show_route_count;
+        self
+    }
+
+    /// Shows the robot's competition mode at the bottom of the display, along with how long the
+    /// current autonomous or driver control period has been running.
+    ///
+    /// The mode is one of "Disconnected", "Disabled", "Auton" or "Driver", which is useful for
+    /// confirming that a field control cable is being seen during practice matches. The strip is
+    /// only redrawn when its text changes. Like the [battery indicator], it takes up the bottom
+    /// 20 pixels of the display, and routes are shrunk to fit in the remaining space. Disabled by
+    /// default.
+    ///
+    /// [battery indicator]: SimpleSelectBuilder::show_battery
+    pub fn show_competition_status(mut self, show_competition_status: bool) -> Self {
+        self.show_competition_status = show_competition_status;
+        self
+    }
+
+    /// Allows the selection to be changed using a controller's arrow buttons.
+    ///
+    /// The up and down buttons move through routes in order, while the left and right buttons
+    /// jump between columns.
+    pub fn controller(mut self, controller: Controller) -> Self {
+        self.controller = Some(controller);
+        self
+    }
+
+    /// Shows the selected route's name on a line of the [controller]'s screen.
+    ///
+    /// The line is updated whenever the selection changes and when the controller connects, so
+    /// the selection can be checked when the Brain's display can't be seen. Writes are spaced out
+    /// by [`Controller::UPDATE_INTERVAL`], since the controller drops writes that come too
+    /// quickly. Once a route starts running, the line is cleared and left alone for the robot's
+    /// own code until the selection changes again. Names longer than
+    /// [`Controller::MAX_COLUMNS`] characters are cut off. This has no effect unless a controller
+    /// is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line` is 0 or greater than [`Controller::MAX_LINES`]. Lines are 1-indexed.
+    ///
+    /// [controller]: SimpleSelectBuilder::controller
+    pub fn mirror_to_controller(mut self, line: u8) -> Self {
+        assert!(
+            line != 0 && usize::from(line) <= Controller::MAX_LINES,
+            "Invalid controller line."
+        );
+
+        self.controller_line = Some(line);
+        self
+    }
+
+    /// Registers a callback that runs with the new route index whenever the selection changes.
+    ///
+    /// This is called for both touchscreen selections and calls to [`SimpleSelect::select`]. The
+    /// callback can also be set after the selector is built with [`SimpleSelect::on_select`].
+    pub fn on_selection_change(mut self, callback: impl FnMut(usize) + 'static) -> Self {
+        self.on_selection_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Persists the selected route to a file, restoring it when the selector is built.
+    ///
+    /// The route is saved by its [ID](Route::id), so the selection is kept even if routes are
+    /// reordered. This is typically a path on the Brain's SD card. If the file is missing or
+    /// doesn't match any route, the selector falls back to the first route.
+    pub fn persist_to(mut self, path: impl Into<PathBuf>) -> Self {
+        self.persist_to = Some(path.into());
+        self
+    }
+
+    /// Prints the list of routes over serial whenever the selection changes.
+    ///
+    /// Each route is printed on its own line with its index, and the selected route is marked
+    /// and highlighted in green using ANSI escape codes. Routes that can't currently be selected
+    /// are dimmed. This gives a text-based view of the selector for teams with a terminal
+    /// connected to the Brain, and is useful for debugging. Disabled by default.
+    pub fn mirror_to_serial(mut self, mirror_to_serial: bool) -> Self {
+        self.mirror_to_serial = mirror_to_serial;
+        self
+    }
+
+    /// Reports selection changes and route execution to a [telemetry receiver].
+    ///
+    /// [telemetry receiver]: SelectorTelemetry
+    pub fn telemetry(mut self, telemetry: impl SelectorTelemetry + 'static) -> Self {
+        self.telemetry = Some(Rc::new(telemetry));
+        self
+    }
+
+    /// Controls whether the [running view] is shown while a route is running.
+    ///
+    /// Enabled by default.
+    ///
+    /// [running view]: SimpleSelect::show_running_view
+    pub fn running_view(mut self, running_view: bool) -> Self {
+        self.running_view = running_view;
+        self
+    }
+
+    /// Requires routes to be tapped twice before they are selected.
+    ///
+    /// When enabled, the first tap highlights a route and a second tap within the
+    /// [confirmation timeout] selects it. This prevents accidental selections from brushing
+    /// against the screen. Disabled by default.
+    ///
+    /// [confirmation timeout]: SimpleSelectBuilder::confirmation_timeout
+    pub fn require_confirmation(mut self, require_confirmation: bool) -> Self {
+        self.require_confirmation = require_confirmation;
+        self
+    }
+
+    /// Asks for confirmation in a modal before a tapped route is selected.
+    ///
+    /// When enabled, tapping a route shows its name and description along with "Confirm" and
+    /// "Cancel" buttons, and the route is only selected once "Confirm" is tapped. Tapping anywhere
+    /// else cancels. Routes behind the modal can't be tapped while it is open. This takes the
+    /// place of [requiring a second tap]. Routes selected through [`SimpleSelect::select`] and
+    /// other methods are selected immediately. Disabled by default.
+    ///
+    /// [requiring a second tap]: SimpleSelectBuilder::require_confirmation
+    pub fn confirmation_modal(mut self, confirmation_modal: bool) -> Self {
+        self.confirmation_modal = confirmation_modal;
+        self
+    }
+
+    /// Sets how long a highlighted route waits for a confirming tap before reverting.
+    ///
+    /// This only has an effect if [confirmation is required]. Defaults to 1.5 seconds.
+    ///
+    /// [confirmation is required]: SimpleSelectBuilder::require_confirmation
+    pub fn confirmation_timeout(mut self, timeout: Duration) -> Self {
+        self.confirmation_timeout = timeout;
+        self
+    }
+
+    /// Sets how long a route must be held down to open its context menu.
+    ///
+    /// Holding a route opens a menu with options to select it, show a panel with its description
+    /// and whether it can currently be selected, or cancel. Tapping anywhere outside of the menu
+    /// closes it. Defaults to 800 milliseconds.
+    pub fn long_press_threshold(mut self, threshold: Duration) -> Self {
+        self.long_press_threshold = threshold;
+        self
+    }
+
+    /// Shows a search row above the route list for filtering routes by name.
+    ///
+    /// The search query is typed using the [controller]: pushing the left joystick up or down
+    /// scrolls through letters and digits, the A button adds the current character, and the B
+    /// button deletes the last one. Routes whose names don't contain the query are grayed out and
+    /// skipped when changing the selection. The search row takes up 20 pixels above the route
+    /// list, and routes are shrunk to fit in the remaining space. Disabled by default.
+    ///
+    /// [controller]: SimpleSelectBuilder::controller
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// Registers a callback that receives the raw coordinates and state of every touch.
+    ///
+    /// The callback runs on each refresh of the selector while the display is being touched. This
+    /// is useful for diagnosing touches that register on the wrong route.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let selector = SimpleSelect::builder(routes)
+    ///     .log_touches(|x, y, state| println!("Touch at ({x}, {y}): {state:?}"))
+    ///     .build(peripherals.display);
+    /// ```
+    #[cfg(feature = "debug-logging")]
+    pub fn log_touches(mut self, logger: impl Fn(i16, i16, TouchState) + 'static) -> Self {
+        self.touch_logger = Some(Box::new(logger));
+        self
+    }
+
+    /// Shows each route [category] as a single cell that opens a page of its routes.
+    ///
+    /// The first page lists routes without a category, followed by one cell for each category.
+    /// Tapping a category shows its routes along with a back button, and pages with more entries
+    /// than cells are split across several screens. Selecting a route with the controller or
+    /// [`SimpleSelect::select`] opens the page containing it. Disabled by default.
+    ///
+    /// [category]: Route::category
+    pub fn group_by_category(mut self, group_by_category: bool) -> Self {
+        self.group_by_category = group_by_category;
+        self
+    }
+
+    /// Slides the routes in from the right edge of the display when the selector starts drawing.
+    ///
+    /// The animation takes about a third of a second, which confirms to drivers that the
+    /// selector has loaded. Touches are ignored until it finishes. Disabled by default.
+    pub fn startup_animation(mut self, startup_animation: bool) -> Self {
+        self.startup_animation = startup_animation;
+        self
+    }
+
+    /// Draws each frame off-screen and shows it all at once, so that routes redrawn one after
+    /// another don't tear.
+    ///
+    /// This switches the display to [`RenderMode::DoubleBuffered`] while the selector is drawing,
+    /// and back to [`RenderMode::Immediate`] whenever it stops, so other code drawing to the
+    /// display isn't affected. The back buffer is a full 480x240 frame at 32 bits per pixel,
+    /// about 450 KiB, which VEXos keeps outside the program's heap. Disabled by default.
+    ///
+    /// [`RenderMode::DoubleBuffered`]: vexide::display::RenderMode::DoubleBuffered
+    /// [`RenderMode::Immediate`]: vexide::display::RenderMode::Immediate
+    pub fn double_buffered(mut self, double_buffered: bool) -> Self {
+        self.double_buffered = double_buffered;
+        self
+    }
+
+    /// Draws an image, such as a team logo, behind the selector from the top-left corner of the
+    /// display.
+    ///
+    /// `pixels` are raw RGB in the same [format as thumbnails](Thumbnail#pixel-format), and the
+    /// image can be up to the full 480x240 display. It shows through every cell drawn in the
+    /// theme's default background color, and is restored whenever those cells are redrawn.
+    /// Selected and highlighted cells, and the header bar, are drawn over it. Each redraw
+    /// converts the part of the image it covers into display colors, which for a full-screen
+    /// image briefly allocates about 450 KiB.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pixels` isn't exactly `width * height * 3` bytes long.
+    pub fn background_image(mut self, pixels: &'static [u8], width: u16, height: u16) -> Self {
+        self.background_image = Some(Thumbnail::new(width, height, pixels));
+        self
+    }
+
+    /// Creates the selector from this configuration and starts drawing it to the display.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the header and footer leave less than one pixel of height for each row of the
+    /// [grid](SimpleSelectBuilder::grid).
+    pub fn build(self, display: Display) -> SimpleSelect<R, N> {
+        SimpleSelect::from_builder(self, display)
+    }
+}
@@ -0,0 +1,147 @@
+use vexide::{
+    display::{Alignment, Display, Font, FontFamily, FontSize, Rect, Text},
+    math::Point2,
+};
+
+use super::{Route, SimpleSelectTheme};
+
+/// Size and position of the context menu, centered on the display.
+const MENU_WIDTH: i16 = 180;
+const MENU_TITLE_HEIGHT: i16 = 28;
+const MENU_OPTION_HEIGHT: i16 = 36;
+const MENU_HEIGHT: i16 = MENU_TITLE_HEIGHT + MENU_OPTION_HEIGHT * 3;
+const MENU_LEFT: i16 = (Display::HORIZONTAL_RESOLUTION - MENU_WIDTH) / 2;
+const MENU_TOP: i16 = (Display::VERTICAL_RESOLUTION - MENU_HEIGHT) / 2;
+
+/// An option in the [`ContextMenu`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ContextMenuOption {
+    /// Selects the route, as if it had been tapped.
+    Select,
+    /// Shows the route's description and status.
+    Details,
+    /// Closes the menu without doing anything.
+    Cancel,
+}
+
+impl ContextMenuOption {
+    /// Every option, in the order they are listed in the menu.
+    const ALL: [Self; 3] = [Self::Select, Self::Details, Self::Cancel];
+
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Select => "Select",
+            Self::Details => "Details",
+            Self::Cancel => "Cancel",
+        }
+    }
+}
+
+/// The menu shown when a route is held down.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct ContextMenu {
+    index: usize,
+}
+
+impl ContextMenu {
+    /// Creates a menu for the route at `index`.
+    pub(crate) const fn new(index: usize) -> Self {
+        Self { index }
+    }
+
+    /// Returns the index of the route the menu was opened for.
+    pub(crate) const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the option at `point`, or `None` if it isn't on one.
+    pub(crate) fn option_at(&self, point: Point2<i16>) -> Option<ContextMenuOption> {
+        let y = point.y - MENU_TOP - MENU_TITLE_HEIGHT;
+
+        if !(MENU_LEFT..MENU_LEFT + MENU_WIDTH).contains(&point.x) || y < 0 {
+            return None;
+        }
+
+        ContextMenuOption::ALL
+            .get((y / MENU_OPTION_HEIGHT) as usize)
+            .copied()
+    }
+
+    /// Draws the menu over the route grid.
+    ///
+    /// The "Select" option is grayed out if `route` can't currently be selected.
+    pub(crate) fn render<R>(
+        &self,
+        display: &mut Display,
+        theme: &SimpleSelectTheme,
+        route: &Route<R>,
+    ) {
+        let panel =
+            Rect::from_dimensions([MENU_LEFT, MENU_TOP], MENU_WIDTH as u16, MENU_HEIGHT as u16);
+        display.fill(&panel, theme.background_default);
+
+        // Title bar with the route's name.
+        display.fill(
+            &Rect::from_dimensions(
+                [MENU_LEFT, MENU_TOP],
+                MENU_WIDTH as u16,
+                MENU_TITLE_HEIGHT as u16,
+            ),
+            theme.background_selected,
+        );
+        display.draw_text(
+            &Text::from_string_aligned(
+                route.name.as_ref(),
+                Font::new(FontSize::SMALL, FontFamily::Proportional),
+                [MENU_LEFT + 10, MENU_TOP + MENU_TITLE_HEIGHT / 2],
+                Alignment::Start,
+                Alignment::Center,
+            ),
+            theme.text_selected,
+            None,
+        );
+
+        for (i, option) in ContextMenuOption::ALL.into_iter().enumerate() {
+            let top = MENU_TOP + MENU_TITLE_HEIGHT + i as i16 * MENU_OPTION_HEIGHT;
+            let (background_color, text_color) =
+                if option == ContextMenuOption::Select && !route.is_selectable() {
+                    (theme.background_disabled, theme.text_disabled)
+                } else {
+                    (theme.background_default, theme.text_default)
+                };
+
+            display.fill(
+                &Rect::from_dimensions(
+                    [MENU_LEFT, top],
+                    MENU_WIDTH as u16,
+                    MENU_OPTION_HEIGHT as u16,
+                ),
+                background_color,
+            );
+            display.stroke(
+                &Rect::from_dimensions(
+                    [MENU_LEFT, top],
+                    MENU_WIDTH as u16,
+                    MENU_OPTION_HEIGHT as u16,
+                ),
+                theme.border,
+            );
+            display.draw_text(
+                &Text::from_string_aligned(
+                    option.label(),
+                    Font::new(FontSize::SMALL, FontFamily::Proportional),
+                    [
+                        Display::HORIZONTAL_RESOLUTION / 2,
+                        top + MENU_OPTION_HEIGHT / 2,
+                    ],
+                    Alignment::Center,
+                    Alignment::Center,
+                ),
+                text_color,
+                None,
+            );
+        }
+
+        display.stroke(&panel, theme.border);
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use super::Route;
+
+/// Serializable description of a route.
+///
+/// Routes themselves can't be serialized because they contain function pointers, so this type
+/// stores a route's index and name instead. This is useful for saving the selected route to a file
+/// and restoring it later through [`SimpleSelect::select_from_descriptor`].
+///
+/// [`SimpleSelect::select_from_descriptor`]: super::SimpleSelect::select_from_descriptor
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct RouteDescriptor {
+    /// Index of the route in its selector.
+    pub index: usize,
+
+    /// Display name of the route.
+    pub name: String,
+}
+
+impl<R> Route<R> {
+    /// Returns a serializable [descriptor](RouteDescriptor) for this route at the given index.
+    pub fn descriptor(&self, index: usize) -> RouteDescriptor {
+        RouteDescriptor {
+            index,
+            name: self.name.to_string(),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::{error::Error, fmt};
+
+use super::RouteId;
+
+/// Error returned when a route cannot be selected.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SelectError {
+    /// The route at this index is disabled or its precondition does not allow it to be selected.
+    Unavailable {
+        /// Index of the route that was requested.
+        index: usize,
+    },
+
+    /// No route has this ID.
+    UnknownId(RouteId),
+}
+
+impl fmt::Display for SelectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable { index } => {
+                write!(f, "route {index} is disabled and cannot be selected")
+            }
+            Self::UnknownId(id) => write!(f, "no route has the ID {id}"),
+        }
+    }
+}
+
+impl Error for SelectError {}
@@ -0,0 +1,29 @@
+/// Arrangement of route entries on the [`SimpleSelect`] display.
+///
+/// [`SimpleSelect`]: super::SimpleSelect
+#[non_exhaustive]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SimpleSelectLayout {
+    /// Two columns of six rows, filled top-to-bottom starting from the left column.
+    ///
+    /// This mirrors the layout of the VEXos program dashboard. The number of rows and columns can
+    /// be changed through [`SimpleSelectBuilder::grid`].
+    ///
+    /// [`SimpleSelectBuilder::grid`]: super::SimpleSelectBuilder::grid
+    #[default]
+    Grid,
+
+    /// A single column of larger rows that scrolls, with buttons on the right edge for scrolling
+    /// up and down.
+    ///
+    /// The list can also be scrolled by dragging it. Only a tap that stays in place selects a
+    /// route, so dragging never changes the selection. This suits routes with long names.
+    List,
+
+    /// A single column of up to six routes on the left half, with a panel on the right half
+    /// showing the selected route's name, description, estimated points and duration.
+    ///
+    /// More routes than fit are split across pages, with buttons for turning the page at the
+    /// bottom of the panel.
+    Detail,
+}
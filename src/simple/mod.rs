@@ -3,17 +3,20 @@
 //! ![Screenshot of the `SimpleSelect` menu showing two routes](https://i.imgur.com/qM9qMsd.png)
 //!
 //! [`SimpleSelect`] is a barebones and lightweight autonomous selector that allows picking
-//! between at most 12 autonomous routes using the V5 Brain's display and touchscreen.
+//! between autonomous routes using the V5 Brain's display and touchscreen. Up to 12 routes fit on
+//! the screen at once, and longer lists are split across pages or can be
+//! [grouped by category](SimpleSelectBuilder::group_by_category).
 //!
 //! The selector provides a user interface that mimicks the appearance of other VEXos
 //! dashboards, with basic support for color themes through the [`SimpleSelect::new_with_theme`]
-//! function.
+//! function. Two themes are provided: [`THEME_DARK`], which is used by default, and
+//! [`THEME_LIGHT`], which is easier to read in bright rooms.
 //!
 //! # Examples
 //!
 //! Robot with two autonomous routes using [`SelectCompete`](crate::compete::SelectCompete).
 //!
-//! ```
+//! ```no_run
 //! use vexide::prelude::*;
 //! use autons::{
 //!     prelude::*,
@@ -49,311 +52,3607 @@
 //! }
 //! ```
 
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    cmp::{Ordering, Reverse},
+    ops::{Deref, DerefMut, Range},
+    path::PathBuf,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use vexide::{
-    display::{Display, Font, FontFamily, FontSize, Line, Rect, Text, TouchState},
+    color::Color,
+    competition::{self, CompetitionMode},
+    controller::{Controller, ControllerConnection},
+    display::{
+        Alignment, Circle, Display, Font, FontFamily, FontSize, Line, Rect, RenderMode, Text,
+        TouchState,
+    },
+    math::Point2,
     task::{self, Task},
     time::sleep,
 };
 
-use crate::Selector;
+use self::context_menu::{ContextMenu, ContextMenuOption};
+use crate::{ColorExt, Selector, SelectorStatus, SelectorTelemetry};
 
+mod alliance;
+mod alliance_select;
+mod builder;
+mod context_menu;
+#[cfg(feature = "serde")]
+mod descriptor;
+mod error;
+mod layout;
 mod route;
+mod route_list;
 mod theme;
+mod thumbnail;
 
+pub use alliance::*;
+pub use alliance_select::*;
+pub use builder::*;
+#[cfg(feature = "serde")]
+pub use descriptor::*;
+pub use error::*;
+pub use layout::*;
 pub use route::*;
+pub use route_list::*;
 pub use theme::*;
+pub use thumbnail::*;
+
+/// Length of the autonomous period in a head-to-head match.
+const AUTONOMOUS_PERIOD: Duration = Duration::from_secs(15);
+
+/// Length of the autonomous period in a skills run.
+const SKILLS_PERIOD: Duration = Duration::from_secs(60);
+
+/// How strongly unselected routes are tinted by their alliance's color.
+const ALLIANCE_TINT: f32 = 0.25;
+
+/// Width of the stripe drawn along the left edge of routes with an accent color, in pixels.
+const ACCENT_STRIPE_WIDTH: u16 = 4;
+
+/// How far a route's accent color is faded towards the background when it is selected.
+const ACCENT_DIM: f32 = 0.3;
+
+/// Minimum contrast ratio between a selected route's text and its accent background.
+const MIN_TEXT_CONTRAST: f32 = 4.5;
+
+/// Height of the optional header bar above the route grid, in pixels.
+const HEADER_HEIGHT: i16 = 20;
+
+/// Height of the optional footer below the route grid, in pixels.
+const FOOTER_HEIGHT: i16 = 20;
+
+/// How often the battery indicator is refreshed.
+const BATTERY_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How often the elapsed time on the running view is refreshed.
+const TIMER_REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Height of the optional search row above the route grid, in pixels.
+const SEARCH_ROW_HEIGHT: i16 = 20;
+
+/// Maximum length of a search query, in characters.
+const MAX_SEARCH_LEN: usize = 16;
+
+/// Characters that can be typed into the search row, in scrolling order.
+const SEARCH_CHARACTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 ";
+
+/// How far the left joystick must be pushed to scroll to the next search character.
+const SEARCH_STICK_THRESHOLD: f64 = 0.5;
+
+/// Position and size of the route detail modal.
+const MODAL_LEFT: i16 = 40;
+const MODAL_TOP: i16 = 24;
+const MODAL_WIDTH: i16 = Display::HORIZONTAL_RESOLUTION - MODAL_LEFT * 2;
+const MODAL_HEIGHT: i16 = Display::VERTICAL_RESOLUTION - MODAL_TOP * 2;
+
+/// Size and position of the buttons for confirming a selection in the route detail modal.
+const MODAL_BUTTON_WIDTH: i16 = 100;
+const MODAL_BUTTON_HEIGHT: i16 = 28;
+const MODAL_CANCEL_CENTER: [i16; 2] = [
+    Display::HORIZONTAL_RESOLUTION / 2 - 60,
+    MODAL_TOP + MODAL_HEIGHT - 22,
+];
+const MODAL_CONFIRM_CENTER: [i16; 2] = [
+    Display::HORIZONTAL_RESOLUTION / 2 + 60,
+    MODAL_TOP + MODAL_HEIGHT - 22,
+];
+
+/// How long each column takes to slide in during the startup animation.
+const STARTUP_SLIDE_DURATION: Duration = Duration::from_millis(300);
+
+/// How long the second column waits before sliding in during the startup animation.
+const STARTUP_COLUMN_DELAY: Duration = Duration::from_millis(50);
+
+/// How far a touch must move, in pixels, before it scrolls the list layout instead of selecting.
+const DRAG_THRESHOLD: i16 = 10;
+
+/// How far secondary text, such as the route count, is faded towards the background.
+const SECONDARY_TEXT_DIM: f32 = 0.4;
+
+/// How far colors are faded towards the background while the selector is locked.
+const LOCKED_DIM: f32 = 0.35;
+
+/// A predicate deciding which routes are shown, set through [`SimpleSelect::set_filter`].
+type RouteFilter<R> = Box<dyn Fn(&Route<R>) -> bool>;
+
+/// Returns the relative luminance of a color, as defined by WCAG.
+fn luminance(color: Color) -> f32 {
+    let channel = |value: u8| {
+        let value = f32::from(value) / 255.0;
+
+        if value <= 0.039_28 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * channel(color.r) + 0.7152 * channel(color.g) + 0.0722 * channel(color.b)
+}
+
+/// Returns the WCAG contrast ratio between two colors, from 1 to 21.
+fn contrast(a: Color, b: Color) -> f32 {
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Returns `preferred` if it is readable on `background`, and otherwise black or white,
+/// whichever contrasts more.
+fn readable_text(preferred: Color, background: Color) -> Color {
+    if contrast(preferred, background) >= MIN_TEXT_CONTRAST {
+        preferred
+    } else if contrast(Color::BLACK, background) > contrast(Color::WHITE, background) {
+        Color::BLACK
+    } else {
+        Color::WHITE
+    }
+}
+
+/// Greedily wraps `text` into lines of fewer than `line_characters` characters, breaking only
+/// between words.
+fn wrap_text(text: &str, line_characters: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for word in text.split_whitespace() {
+        match lines.last_mut() {
+            Some(line) if line.len() + word.len() < line_characters => {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_owned()),
+        }
+    }
+
+    lines
+}
+
+/// An in-progress fade between two background colors of a route cell.
+#[derive(Clone, Copy)]
+struct Transition {
+    from: Color,
+    to: Color,
+    text: Color,
+    started_at: Instant,
+}
+
+/// Tracks the background color of each route cell for [`AnimatedTheme`] transitions.
+struct Transitions<const N: usize> {
+    duration: Duration,
+    displayed: [Option<Color>; N],
+    active: [Option<Transition>; N],
+}
+
+impl<const N: usize> Transitions<N> {
+    const fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            displayed: [None; N],
+            active: [None; N],
+        }
+    }
+
+    /// Forgets every cell's color, so the next draw of each cell happens without a transition.
+    const fn reset(&mut self) {
+        self.displayed = [None; N];
+        self.active = [None; N];
+    }
+
+    /// Starts a transition of the cell at `index` towards `to`, returning the background color
+    /// the cell should be drawn with now.
+    fn begin(&mut self, index: usize, to: Color, text: Color) -> Color {
+        if let Some(from) = self.displayed[index]
+            && from != to
+            && !self.duration.is_zero()
+        {
+            self.active[index] = Some(Transition {
+                from,
+                to,
+                text,
+                started_at: Instant::now(),
+            });
+
+            from
+        } else {
+            self.active[index] = None;
+            self.displayed[index] = Some(to);
+
+            to
+        }
+    }
+}
+
+/// What a cell in the grid shows.
+#[derive(Clone, Copy, PartialEq)]
+enum Slot {
+    /// The route at this index.
+    Route(usize),
+    /// A category of routes, which opens its page when tapped.
+    Group(&'static str),
+    /// A button that returns to the first page.
+    Back,
+    /// A button that shows the previous page of a list too long to fit in the grid.
+    PreviousPage,
+    /// A button that shows the next page of a list too long to fit in the grid.
+    NextPage,
+    /// A cell with nothing in it.
+    Empty,
+}
+
+/// Positions of route cells in the grid, the single-column list, or the single column beside the
+/// detail panel.
+struct GridMetrics {
+    /// Y coordinate of the top of the first row.
+    top: i16,
+    /// Height of each row, including the gridline beneath it.
+    row_height: i16,
+    /// Number of rows shown at once.
+    rows: usize,
+    /// Width of each cell, including the gridline to its right.
+    cell_width: i16,
+    /// Number of columns of cells, including the detail panel in the detail layout.
+    columns: usize,
+    /// Whether routes are shown in a scrolling list rather than a grid.
+    list: bool,
+    /// Whether the right column is a panel showing the selected route's details.
+    detail: bool,
+    /// Whether routes are grouped into pages by category.
+    grouped: bool,
+    /// The category whose routes are listed, or `None` on the first page.
+    category: Option<&'static str>,
+    /// Everything listed for the category, which may span several pages.
+    entries: Vec<Slot>,
+    /// Which page of `entries` is shown, or in the list layout, the first entry shown.
+    page_number: usize,
+    /// What each cell on the current page shows, in order.
+    slots: Vec<Slot>,
+    /// How far each column is shifted to the right, used for the startup animation.
+    slide: Vec<i16>,
+    /// Image drawn behind the cells from the top-left corner of the display, if any.
+    background: Option<Thumbnail>,
+}
+
+impl GridMetrics {
+    /// Number of rows in the detail layout.
+    const DETAIL_ROWS: usize = 6;
+
+    /// Smallest height of each row in the list layout.
+    const LIST_ROW_HEIGHT: i16 = 48;
+
+    /// Width of the scroll buttons on the right edge of the list layout.
+    const SCROLL_BUTTON_WIDTH: i16 = 48;
+
+    /// Fits the grid into the space between `top` and `bottom` pixels from the bottom edge.
+    ///
+    /// `grid` is the number of rows and columns in the grid layout, and is ignored by the others.
+    fn new(
+        top: i16,
+        bottom: i16,
+        grouped: bool,
+        layout: SimpleSelectLayout,
+        grid: [usize; 2],
+        background: Option<Thumbnail>,
+    ) -> Self {
+        let height = Display::VERTICAL_RESOLUTION - top - bottom;
+        let [rows, columns] = match layout {
+            SimpleSelectLayout::Grid => grid,
+            SimpleSelectLayout::List => [(height / Self::LIST_ROW_HEIGHT) as usize, 1],
+            SimpleSelectLayout::Detail => [Self::DETAIL_ROWS, 2],
+        };
+        let list = matches!(layout, SimpleSelectLayout::List);
+        let detail = matches!(layout, SimpleSelectLayout::Detail);
+        let cell_width = if list {
+            Display::HORIZONTAL_RESOLUTION - Self::SCROLL_BUTTON_WIDTH
+        } else {
+            Display::HORIZONTAL_RESOLUTION / columns as i16
+        };
+
+        Self {
+            top,
+            row_height: height / rows as i16,
+            rows,
+            cell_width,
+            columns,
+            list,
+            detail,
+            grouped,
+            category: None,
+            entries: Vec::new(),
+            page_number: 0,
+            slots: Vec::new(),
+            slide: vec![0; columns],
+            background,
+        }
+    }
+
+    /// Returns the number of cells shown at once.
+    const fn cells(&self) -> usize {
+        self.rows * self.columns
+    }
+
+    /// Returns the number of entries that fit on a single page with no page buttons.
+    ///
+    /// In the detail layout, only the left column holds entries.
+    const fn page_capacity(&self) -> usize {
+        if self.detail { self.rows } else { self.cells() }
+    }
+
+    /// Returns the number of entries on each page of a grid that gives up two cells for page
+    /// buttons.
+    ///
+    /// The detail layout puts its page buttons beneath the panel instead, so every row of the
+    /// left column still holds an entry.
+    const fn entries_per_page(&self) -> usize {
+        if self.detail {
+            self.rows
+        } else if self.cells() > 2 {
+            self.cells() - 2
+        } else {
+            // Grids this small can't show anything beside the page buttons.
+            1
+        }
+    }
+
+    /// Lists the routes in `category`, or the first page if `category` is `None`, starting from
+    /// the first page of the list.
+    ///
+    /// Without grouping, every route is listed together.
+    fn show_page<R>(&mut self, routes: &[Route<R>], category: Option<&'static str>) {
+        self.category = category.filter(|_| self.grouped);
+        self.entries.clear();
+
+        // Routes hidden by a filter are left out, so the remaining routes close up the gaps.
+        let shown = routes
+            .iter()
+            .enumerate()
+            .filter(|(_, route)| route.matches_filter);
+
+        if !self.grouped {
+            self.entries
+                .extend(shown.map(|(index, _)| Slot::Route(index)));
+        } else {
+            if self.category.is_some() {
+                self.entries.push(Slot::Back);
+            }
+
+            for (index, route) in shown {
+                if route.category == self.category {
+                    self.entries.push(Slot::Route(index));
+                } else if self.category.is_none()
+                    && let Some(group) = route.category
+                    && !self.entries.contains(&Slot::Group(group))
+                {
+                    self.entries.push(Slot::Group(group));
+                }
+            }
+        }
+
+        self.turn_to(0);
+    }
+
+    /// Lists the routes on the same page as the route at `index`, and shows the part of the list
+    /// containing it.
+    fn show_route<R>(&mut self, routes: &[Route<R>], index: usize) {
+        self.show_page(routes, routes[index].category);
+
+        let Some(position) = self
+            .entries
+            .iter()
+            .position(|&entry| entry == Slot::Route(index))
+        else {
+            return;
+        };
+
+        if self.list {
+            self.turn_to((position + 1).saturating_sub(self.rows));
+        } else if self.page_count() > 1 {
+            self.turn_to(position / self.entries_per_page());
+        }
+    }
+
+    /// Returns how many pages the current list is split across, or in the list layout, how many
+    /// positions it can be scrolled to.
+    const fn page_count(&self) -> usize {
+        if self.list {
+            self.entries.len().saturating_sub(self.rows) + 1
+        } else if self.entries.len() <= self.page_capacity() {
+            1
+        } else {
+            self.entries.len().div_ceil(self.entries_per_page())
+        }
+    }
+
+    /// Returns whether the route at `index` is listed before ([`Ordering::Less`]) or after
+    /// ([`Ordering::Greater`]) the part of the list that is shown, or `None` if it isn't listed.
+    fn direction_of(&self, index: usize) -> Option<Ordering> {
+        let position = self
+            .entries
+            .iter()
+            .position(|&entry| entry == Slot::Route(index))?;
+
+        let (first, shown) = if self.list {
+            (self.page_number, self.rows)
+        } else if self.page_count() == 1 {
+            (0, self.entries.len())
+        } else {
+            (
+                self.page_number * self.entries_per_page(),
+                self.entries_per_page(),
+            )
+        };
+
+        Some(if position < first {
+            Ordering::Less
+        } else if position >= first + shown {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        })
+    }
+
+    /// Shows a page of the current list, wrapping around past the last page.
+    ///
+    /// Lists too long to fit in the grid give up two cells in the bottom row for previous and next
+    /// page buttons, or in the detail layout, the bottom two cells of the panel. In the list layout,
+    /// this instead scrolls so that the entry at `page_number` is at the top, stopping at the end
+    /// of the list.
+    fn turn_to(&mut self, page_number: usize) {
+        self.slots.clear();
+
+        if self.list {
+            self.page_number = page_number.min(self.page_count() - 1);
+            self.slots.extend(
+                self.entries
+                    .iter()
+                    .skip(self.page_number)
+                    .take(self.rows)
+                    .copied(),
+            );
+            return;
+        }
+
+        self.page_number = page_number % self.page_count();
+
+        if self.page_count() == 1 {
+            self.slots.extend_from_slice(&self.entries);
+            return;
+        }
+
+        let mut entries = self
+            .entries
+            .iter()
+            .skip(self.page_number * self.entries_per_page())
+            .take(self.entries_per_page())
+            .copied();
+
+        // A single column has nowhere else to put the previous page button, so it goes above the
+        // next page button.
+        let previous_cell = if self.detail || self.columns == 1 {
+            self.cells() - 2
+        } else {
+            self.rows - 1
+        };
+
+        for cell in 0..self.cells() {
+            self.slots.push(if cell == previous_cell {
+                Slot::PreviousPage
+            } else if cell == self.cells() - 1 {
+                Slot::NextPage
+            } else if self.detail && cell >= self.rows {
+                Slot::Empty
+            } else {
+                entries.next().unwrap_or(Slot::Empty)
+            });
+        }
+    }
+
+    /// Shows the previous page, or scrolls the list up by a screen.
+    fn previous_page(&mut self) {
+        if self.list {
+            self.turn_to(self.page_number.saturating_sub(self.rows));
+        } else {
+            self.turn_to(self.page_number + self.page_count() - 1);
+        }
+    }
+
+    /// Shows the next page, or scrolls the list down by a screen.
+    fn next_page(&mut self) {
+        if self.list {
+            self.turn_to(self.page_number + self.rows);
+        } else {
+            self.turn_to(self.page_number + 1);
+        }
+    }
+
+    /// Returns the button at `point`, if any.
+    ///
+    /// The scroll buttons of the list layout are reported as [`Slot::PreviousPage`] and
+    /// [`Slot::NextPage`].
+    fn button_at(&self, point: Point2<i16>) -> Option<Slot> {
+        let bottom = self.top + self.row_height * self.rows as i16;
+
+        if self.list && point.x >= self.cell_width && point.y >= self.top && point.y < bottom {
+            return Some(if point.y < (self.top + bottom) / 2 {
+                Slot::PreviousPage
+            } else {
+                Slot::NextPage
+            });
+        }
+
+        self.index_at(point)
+            .and_then(|slot| self.slots.get(slot).copied())
+            .filter(|slot| !matches!(slot, Slot::Route(_) | Slot::Empty))
+    }
+
+    /// Returns the cell showing the route at `index`, if it is on the current page.
+    fn slot_of(&self, index: usize) -> Option<usize> {
+        self.slots
+            .iter()
+            .position(|&slot| slot == Slot::Route(index))
+    }
+
+    /// Returns the index of the route shown in the cell at `slot`, if any.
+    fn route_at(&self, slot: usize) -> Option<usize> {
+        match self.slots.get(slot) {
+            Some(Slot::Route(index)) => Some(*index),
+            _ => None,
+        }
+    }
+
+    /// Returns the top-left corner of the cell at `index`.
+    fn cell_origin(&self, index: usize) -> [i16; 2] {
+        [
+            (index / self.rows) as i16 * self.cell_width + self.slide[index / self.rows],
+            self.top + (index % self.rows) as i16 * self.row_height,
+        ]
+    }
+
+    /// Fills `rect` with `color`, except where it overlaps the background image and `color` is
+    /// the theme's default background, which shows the image instead.
+    fn fill(&self, display: &mut Display, theme: &SimpleSelectTheme, rect: Rect, color: Color) {
+        display.fill(&rect, color);
+
+        if let Some(background) = self.background
+            && color == theme.background_default
+        {
+            background.draw(display, [0, 0], rect);
+        }
+    }
+
+    /// Returns the index of the cell containing `point`, if any.
+    fn index_at(&self, point: Point2<i16>) -> Option<usize> {
+        let row = (point.y - self.top).div_euclid(self.row_height);
+
+        if point.y < self.top
+            || row >= self.rows as i16
+            || point.x < 0
+            || point.x >= self.cell_width * self.columns as i16
+        {
+            return None;
+        }
+
+        Some((point.x / self.cell_width) as usize * self.rows + row as usize)
+    }
+}
+
+/// Display settings used by the task that draws a [`SimpleSelect`].
+struct RenderConfig {
+    theme: SimpleSelectTheme,
+    transition: Duration,
+    layout: SimpleSelectLayout,
+    grid: [usize; 2],
+    header: Option<&'static str>,
+    show_battery: bool,
+    show_route_count: bool,
+    show_competition_status: bool,
+    controller: Option<RefCell<Controller>>,
+    controller_line: Option<u8>,
+    require_confirmation: bool,
+    confirmation_modal: bool,
+    confirmation_timeout: Duration,
+    long_press_threshold: Duration,
+    searchable: bool,
+    group_by_category: bool,
+    startup_animation: bool,
+    double_buffered: bool,
+    background_image: Option<Thumbnail>,
+}
+
+impl RenderConfig {
+    /// Returns the height of the bars above and below the routes, in pixels.
+    const fn margins(&self) -> [i16; 2] {
+        let top = if self.header.is_some() {
+            HEADER_HEIGHT
+        } else {
+            0
+        } + if self.searchable {
+            SEARCH_ROW_HEIGHT
+        } else {
+            0
+        };
+        let bottom = if self.show_battery || self.show_route_count || self.show_competition_status {
+            FOOTER_HEIGHT
+        } else {
+            0
+        };
+
+        [top, bottom]
+    }
+}
+
+/// The display drawn to by the render task, which is switched back to immediate rendering once
+/// dropped so that nothing drawn to it afterwards waits on a [`Display::render`] call.
+struct RenderDisplay(Display);
+
+impl Deref for RenderDisplay {
+    type Target = Display;
+
+    fn deref(&self) -> &Display {
+        &self.0
+    }
+}
+
+impl DerefMut for RenderDisplay {
+    fn deref_mut(&mut self) -> &mut Display {
+        &mut self.0
+    }
+}
+
+impl Drop for RenderDisplay {
+    fn drop(&mut self) {
+        if self.0.render_mode() != RenderMode::Immediate {
+            self.0.set_render_mode(RenderMode::Immediate);
+        }
+    }
+}
 
 struct SelectorState<R: 'static, const N: usize> {
     routes: [Route<R>; N],
     selection: usize,
     dirty_selection: Option<usize>,
+    needs_full_redraw: bool,
+    last_changed: Option<Instant>,
+    pending_confirm: Option<(usize, Instant)>,
+    on_selection_change: Option<Box<dyn FnMut(usize)>>,
+    persist_to: Option<PathBuf>,
+    mirror_to_serial: bool,
+    telemetry: Option<Rc<dyn SelectorTelemetry>>,
+    running_since: Option<Instant>,
+    timed_out: bool,
+    error: Option<String>,
+    long_press: Option<LongPress>,
+    filter_query: String,
+    filter: Option<RouteFilter<R>>,
+    needs_reflow: bool,
+    locked: bool,
+    controller_mirror: ControllerMirror,
+    #[cfg(feature = "debug-logging")]
+    touch_logger: Option<TouchLogger>,
+}
+
+/// Progress of mirroring the selected route's name to the controller screen.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum ControllerMirror {
+    /// The selected route's name needs to be written to the controller.
+    Stale,
+    /// The controller shows the selected route's name.
+    Shown,
+    /// The line needs to be cleared, since a route has started running.
+    Clear,
+    /// The line has been cleared, and is left alone until the selection changes.
+    Cleared,
+}
+
+/// A touch on the list layout that may turn into a drag that scrolls the list.
+#[derive(Clone, Copy)]
+struct Drag {
+    start_y: i16,
+    start_scroll: usize,
+    /// Whether the touch has moved far enough to scroll instead of selecting a route.
+    scrolling: bool,
+}
+
+/// A touch that is being held on a route, which opens the context menu once held long enough.
+#[derive(Clone, Copy)]
+struct LongPress {
+    started_at: Instant,
+    index: usize,
+}
+
+/// Progress of dismissing the route detail modal or context menu.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum DetailModal {
+    /// The modal was just opened, and the long press that opened it hasn't been released yet.
+    Opening,
+    /// The modal is open and waiting for a tap.
+    Open,
+    /// The modal has been tapped, and will close once the touch is released.
+    Closing,
+}
+
+impl<R, const N: usize> SelectorState<R, N> {
+    /// Returns `true` if the route at `index` has been tapped once and is awaiting confirmation.
+    fn is_pending(&self, index: usize) -> bool {
+        self.pending_confirm
+            .is_some_and(|(pending_index, _)| pending_index == index)
+    }
+
+    /// Returns the next route after the selection that can be selected, wrapping around at the
+    /// ends, or the previous one if `forward` is `false`.
+    ///
+    /// Returns `None` if no other route can be selected.
+    fn cycled(&self, forward: bool) -> Option<usize> {
+        (1..N)
+            .map(|offset| {
+                if forward {
+                    (self.selection + offset) % N
+                } else {
+                    (self.selection + N - offset) % N
+                }
+            })
+            .find(|&i| self.routes[i].is_selectable())
+    }
+
+    /// Marks which routes match the search query and route filter, moving the selection to the
+    /// first matching route if the selected route no longer matches.
+    ///
+    /// Returns `true` if the selection changed.
+    fn apply_filter(&mut self) -> bool {
+        let query = self.filter_query.to_lowercase();
+
+        for route in &mut self.routes {
+            route.matches_filter = route.name.to_lowercase().contains(&query)
+                && self.filter.as_ref().is_none_or(|filter| filter(route));
+        }
+
+        self.needs_reflow = true;
+
+        if !self.routes[self.selection].is_selectable()
+            && let Some(index) = self.routes.iter().position(Route::is_selectable)
+        {
+            self.dirty_selection = Some(self.selection);
+            self.selection = index;
+            return true;
+        }
+
+        false
+    }
 }
 
 /// Simple touchscreen-based autonomous route selector.
 ///
 /// `SimpleSelect` is a barebones and lightweight autonomous selector that allows picking
-/// between up to 16 autonomous routes using the V5 brain's display and touchscreen.
+/// between autonomous routes using the V5 brain's display and touchscreen. Routes are shown in a
+/// grid of six rows and two columns by default, which can be changed through
+/// [`SimpleSelectBuilder::grid`]. When there are more routes than cells, the bottom row of the
+/// grid is used for buttons that flip between pages.
 ///
 /// The selector provides a user interface that mimicks the appearance of other VEXos
 /// dashboards, with basic support for color themes through the [`SimpleSelect::new_with_theme`]
-/// function.
+/// function. Further configuration is available through [`SimpleSelect::builder`].
+///
+/// Routes are displayed in order of descending [priority]. This order is fixed when the selector
+/// is constructed, so changing a route's priority afterwards has no effect.
+///
+/// Cloning a `SimpleSelect` creates another handle to the same selector rather than a new one.
+/// Clones share the same state, so a selection made through one clone is visible through all of
+/// the others. The selector's background task keeps running until every clone has been dropped.
 ///
 /// This struct implements the [`Selector`] trait and should be used with the [`SelectCompete`]
 /// trait if using vexide's competition runtime.
 ///
 /// [`SelectCompete`]: crate::compete::SelectCompete
+/// [priority]: Route::priority
 pub struct SimpleSelect<R: 'static, const N: usize> {
     state: Rc<RefCell<SelectorState<R, N>>>,
-    _task: Task<()>,
+    telemetry: Option<Rc<dyn SelectorTelemetry>>,
+    running_view: bool,
+    config: Rc<RenderConfig>,
+    task: Rc<RefCell<Option<Task<()>>>>,
+}
+
+impl<R, const N: usize> Clone for SimpleSelect<R, N> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            telemetry: self.telemetry.clone(),
+            running_view: self.running_view,
+            config: self.config.clone(),
+            task: self.task.clone(),
+        }
+    }
 }
 
 impl<R, const N: usize> SimpleSelect<R, N> {
     /// Creates a new selector from a [`Display`] peripheral and array of routes.
     pub fn new(display: Display, routes: [Route<R>; N]) -> Self {
-        Self::new_with_theme(display, routes, THEME_DARK)
+        SimpleSelectBuilder::new(routes).build(display)
+    }
+
+    /// Creates a new selector that shows routes in a scrollable [list] rather than a grid.
+    ///
+    /// [list]: SimpleSelectLayout::List
+    pub fn new_list(display: Display, routes: [Route<R>; N]) -> Self {
+        SimpleSelectBuilder::new(routes)
+            .layout(SimpleSelectLayout::List)
+            .build(display)
     }
 
     /// Creates a new selector from a [`Display`] peripheral and array of routes with a provided
     /// [custom color theme].
     ///
     /// [custom color theme]: SimpleSelectTheme
-    #[allow(clippy::await_holding_refcell_ref)] // clippy is too dumb to realize we explicitly drop
     pub fn new_with_theme(
-        mut display: Display,
+        display: Display,
+        routes: [Route<R>; N],
+        theme: SimpleSelectTheme,
+    ) -> Self {
+        SimpleSelectBuilder::new(routes).theme(theme).build(display)
+    }
+
+    /// Creates a new selector that shows a compact list of routes beside a [panel] with the
+    /// selected route's details, using a provided [custom color theme].
+    ///
+    /// [panel]: SimpleSelectLayout::Detail
+    /// [custom color theme]: SimpleSelectTheme
+    pub fn new_with_detail(
+        display: Display,
         routes: [Route<R>; N],
         theme: SimpleSelectTheme,
     ) -> Self {
+        SimpleSelectBuilder::new(routes)
+            .theme(theme)
+            .layout(SimpleSelectLayout::Detail)
+            .build(display)
+    }
+
+    /// Creates a new selector from a [`Display`] peripheral and array of routes with a provided
+    /// [animated color theme].
+    ///
+    /// [animated color theme]: AnimatedTheme
+    pub fn new_with_animated_theme(
+        display: Display,
+        routes: [Route<R>; N],
+        theme: AnimatedTheme,
+    ) -> Self {
+        SimpleSelectBuilder::new(routes)
+            .animated_theme(theme)
+            .build(display)
+    }
+
+    /// Creates a [builder] for configuring a selector over an array of routes.
+    ///
+    /// [builder]: SimpleSelectBuilder
+    pub fn builder(routes: [Route<R>; N]) -> SimpleSelectBuilder<R, N> {
+        SimpleSelectBuilder::new(routes)
+    }
+
+    /// Sorts routes by [priority] and checks them for mistakes, warning about routes longer than
+    /// the autonomous period and panicking on duplicate IDs or more than one default route.
+    ///
+    /// [priority]: Route::priority
+    fn prepare_routes(routes: &mut [Route<R>; N]) {
+        // Higher priority routes are shown first. This is a stable sort, so routes with equal
+        // priority stay in the order they were given.
+        routes.sort_by_key(|route| Reverse(route.priority));
+
+        for route in routes.iter() {
+            let period = if route
+                .category
+                .is_some_and(|category| category.eq_ignore_ascii_case("skills"))
+            {
+                SKILLS_PERIOD
+            } else {
+                AUTONOMOUS_PERIOD
+            };
+
+            if let Some(duration) = route.duration
+                && duration > period
+            {
+                println!(
+                    "[autons] warning: route \"{}\" takes {:.1}s, which is longer than the {}s autonomous period",
+                    route.name,
+                    duration.as_secs_f64(),
+                    period.as_secs(),
+                );
+            }
+        }
+
+        // Placeholders can never be selected, so they may share IDs.
+        for (i, route) in routes
+            .iter()
+            .enumerate()
+            .filter(|(_, route)| !route.is_placeholder)
+        {
+            if let Some(duplicate) = routes[..i]
+                .iter()
+                .find(|other| !other.is_placeholder && other.id() == route.id())
+            {
+                panic!(
+                    "Routes \"{}\" and \"{}\" have the same ID ({}). Give one of them a unique ID using `Route::with_id`.",
+                    duplicate.name,
+                    route.name,
+                    route.id(),
+                );
+            }
+        }
+
+        let mut defaults = routes.iter().filter(|route| route.is_default);
+        if let (Some(first), Some(second)) = (defaults.next(), defaults.next()) {
+            panic!(
+                "Routes \"{}\" and \"{}\" are both marked as the default route. Only one route can be the default.",
+                first.name, second.name,
+            );
+        }
+    }
+
+    fn from_builder(builder: SimpleSelectBuilder<R, N>, display: Display) -> Self {
         const {
             assert!(N > 0, "SimpleSelect requires at least one route.");
-            assert!(
-                N <= 12,
-                "SimpleSelect currently only supports up to 12 routes."
-            );
         }
 
-        let state = Rc::new(RefCell::new(SelectorState {
+        let SimpleSelectBuilder {
+            mut routes,
+            theme,
+            transition,
+            layout,
+            grid,
+            header,
+            show_battery,
+            show_route_count,
+            show_competition_status,
+            controller,
+            controller_line,
+            on_selection_change,
+            persist_to,
+            mirror_to_serial,
+            telemetry,
+            running_view,
+            require_confirmation,
+            confirmation_modal,
+            confirmation_timeout,
+            long_press_threshold,
+            searchable,
+            group_by_category,
+            startup_animation,
+            double_buffered,
+            background_image,
+            #[cfg(feature = "debug-logging")]
+            touch_logger,
+        } = builder;
+
+        Self::prepare_routes(&mut routes);
+
+        // Restore the last selection if it was previously saved, otherwise start from the
+        // default route. If no route can be selected, the first route is shown as selected but
+        // `run` won't run it.
+        let selection = persist_to
+            .as_ref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| contents.trim().parse::<u32>().ok())
+            .and_then(|id| {
+                routes
+                    .iter()
+                    .position(|route| !route.is_placeholder && route.id() == RouteId::new(id))
+            })
+            .filter(|&index| routes[index].is_available())
+            .or_else(|| Self::default_index(&routes))
+            .unwrap_or(0);
+
+        let shared = Rc::new(RefCell::new(SelectorState {
             routes,
-            selection: 0,
+            selection,
             dirty_selection: None,
+            needs_full_redraw: true,
+            last_changed: None,
+            pending_confirm: None,
+            on_selection_change,
+            persist_to,
+            mirror_to_serial,
+            telemetry: telemetry.clone(),
+            running_since: None,
+            timed_out: false,
+            error: None,
+            long_press: None,
+            filter_query: String::new(),
+            filter: None,
+            needs_reflow: false,
+            locked: false,
+            controller_mirror: ControllerMirror::Stale,
+            #[cfg(feature = "debug-logging")]
+            touch_logger,
         }));
 
-        Self {
-            state: state.clone(),
-            _task: task::spawn(async move {
-                // Background
-                display.fill(
-                    &Rect::new(
-                        [0, 0],
-                        [Display::HORIZONTAL_RESOLUTION, Display::VERTICAL_RESOLUTION],
-                    ),
-                    theme.background_default,
-                );
+        let config = Rc::new(RenderConfig {
+            theme,
+            transition,
+            layout,
+            grid,
+            header,
+            show_battery,
+            show_route_count,
+            show_competition_status,
+            controller: controller.map(RefCell::new),
+            controller_line,
+            require_confirmation,
+            confirmation_modal,
+            confirmation_timeout,
+            long_press_threshold,
+            searchable,
+            group_by_category,
+            startup_animation,
+            double_buffered,
+            background_image,
+        });
 
-                // Grid lines
-                Self::draw_borders(&mut display, &theme);
+        if matches!(layout, SimpleSelectLayout::Grid) {
+            let [top, bottom] = config.margins();
+            assert!(
+                (Display::VERTICAL_RESOLUTION - top - bottom) / grid[0] as i16 > 0,
+                "SimpleSelect grid rows must be at least one pixel tall."
+            );
+        }
 
-                {
-                    let state = state.borrow();
+        Self {
+            task: Rc::new(RefCell::new(Some(task::spawn(Self::render(
+                shared.clone(),
+                config.clone(),
+                display,
+            ))))),
+            state: shared,
+            telemetry,
+            running_view,
+            config,
+        }
+    }
 
-                    for (i, route) in state.routes.iter().enumerate() {
-                        Self::draw_item(
-                            &mut display,
-                            &theme,
-                            route.name,
-                            i,
-                            i == state.selection,
-                            false,
-                        );
+    /// Draws the selector to the display and handles input until the task running it is dropped.
+    #[allow(clippy::await_holding_refcell_ref)] // clippy is too dumb to realize we explicitly drop
+    async fn render(
+        shared: Rc<RefCell<SelectorState<R, N>>>,
+        config: Rc<RenderConfig>,
+        display: Display,
+    ) {
+        let RenderConfig {
+            ref theme,
+            transition,
+            layout,
+            grid,
+            header,
+            show_battery,
+            show_route_count,
+            show_competition_status,
+            ref controller,
+            controller_line,
+            require_confirmation,
+            confirmation_modal,
+            confirmation_timeout,
+            long_press_threshold,
+            searchable,
+            group_by_category,
+            startup_animation,
+            double_buffered,
+            background_image,
+        } = *config;
+        let locked_theme = theme.dimmed(LOCKED_DIM);
+
+        let [top, bottom] = config.margins();
+        let mut metrics = GridMetrics::new(
+            top,
+            bottom,
+            group_by_category,
+            layout,
+            grid,
+            background_image,
+        );
+        metrics.show_page(&shared.borrow().routes, None);
+
+        // With double buffering, every frame is drawn off-screen and shown by `display.render()`
+        // just before waiting for the next one.
+        let mut display = RenderDisplay(display);
+        if double_buffered {
+            display.set_render_mode(RenderMode::DoubleBuffered);
+        }
+
+        // Start from a blank slate, in case rendering was stopped partway through a frame.
+        shared.borrow_mut().needs_full_redraw = true;
+
+        let mut active_item: Option<usize> = None;
+        let mut battery_drawn_at: Option<Instant> = None;
+        let mut competition_status: Option<(bool, CompetitionMode, Instant)> = None;
+        let mut competition_status_drawn: Option<String> = None;
+        let mut transitions = Transitions::<N>::new(transition);
+        let mut running_view_drawn = false;
+        let mut detail_modal: Option<DetailModal> = None;
+        let mut timer_drawn_at: Option<Instant> = None;
+        let mut search_character = 0;
+        let mut search_stick_held = false;
+        let mut pressed_button: Option<Slot> = None;
+        let mut drag: Option<Drag> = None;
+        let mut confirming: Option<usize> = None;
+        let mut confirm_pressed = false;
+        let mut controller_written_at: Option<Instant> = None;
+        let mut controller_connected = false;
+        let mut context_menu: Option<ContextMenu> = None;
+        let mut menu_pressed: Option<ContextMenuOption> = None;
+        let mut detail_panel_drawn: Option<usize> = None;
+
+        // Slide the routes in from the right edge, with each column trailing the one before it.
+        if startup_animation {
+            let started_at = Instant::now();
+
+            loop {
+                let elapsed = started_at.elapsed();
+
+                for (column, slide) in metrics.slide.iter_mut().enumerate() {
+                    let progress = (elapsed
+                        .saturating_sub(STARTUP_COLUMN_DELAY * column as u32)
+                        .as_secs_f64()
+                        / STARTUP_SLIDE_DURATION.as_secs_f64())
+                    .min(1.0);
+                    let eased = 1.0 - (1.0 - progress).powi(3);
+
+                    *slide = (f64::from(Display::HORIZONTAL_RESOLUTION) * (1.0 - eased)) as i16;
+                }
+
+                Self::draw_all(
+                    &mut display,
+                    theme,
+                    &metrics,
+                    &mut transitions,
+                    header,
+                    &shared.borrow(),
+                    None,
+                );
+
+                if metrics.slide.iter().all(|&slide| slide == 0) {
+                    break;
+                }
+
+                display.render();
+                sleep(Display::REFRESH_INTERVAL).await;
+            }
+        }
+
+        loop {
+            let mut state = shared.borrow_mut();
+
+            // Mirror the selection to the controller's screen, if enabled.
+            if let Some(controller) = controller
+                && let Some(line) = controller_line
+            {
+                let mut controller = controller.borrow_mut();
+
+                // The controller's screen is blank after it reconnects.
+                let connected = controller.connection() != ControllerConnection::Offline;
+                if connected
+                    && !controller_connected
+                    && state.controller_mirror == ControllerMirror::Shown
+                {
+                    state.controller_mirror = ControllerMirror::Stale;
+                }
+                controller_connected = connected;
+
+                // Writes made too soon after the last one are dropped by the controller, so
+                // retry on a later refresh.
+                if connected
+                    && controller_written_at.is_none_or(|written_at| {
+                        written_at.elapsed() >= Controller::UPDATE_INTERVAL
+                    })
+                {
+                    let written = match state.controller_mirror {
+                        ControllerMirror::Stale => {
+                            let name: String = state.routes[state.selection]
+                                .name
+                                .chars()
+                                .take(Controller::MAX_COLUMNS)
+                                .collect();
+
+                            controller
+                                .try_set_text(
+                                    format!("{name:<width$}", width = Controller::MAX_COLUMNS),
+                                    line,
+                                    1,
+                                )
+                                .map(|()| ControllerMirror::Shown)
+                        }
+                        ControllerMirror::Clear => controller
+                            .try_clear_line(line)
+                            .map(|()| ControllerMirror::Cleared),
+                        mirror => Ok(mirror),
+                    };
+
+                    if let Ok(mirror) = written
+                        && mirror != state.controller_mirror
+                    {
+                        state.controller_mirror = mirror;
+                        controller_written_at = Some(Instant::now());
                     }
                 }
+            }
 
-                let mut active_item: Option<usize> = None;
-
-                loop {
-                    let mut state = state.borrow_mut();
-
-                    let touch = display.touch_status();
-                    let touch_index = ((6 * (touch.point.x / (Display::HORIZONTAL_RESOLUTION / 2)))
-                        + touch.point.y / 40) as usize;
-
-                    if matches!(touch.state, TouchState::Held | TouchState::Pressed) {
-                        if active_item
-                            .is_none_or(|prev_active_item| prev_active_item != touch_index)
-                            && touch_index < N
-                        {
-                            if let Some(old_active_item) = active_item {
-                                Self::draw_item(
-                                    &mut display,
-                                    &theme,
-                                    &state.routes[old_active_item].name,
-                                    old_active_item,
-                                    old_active_item == state.selection,
-                                    false,
-                                );
-                            }
+            // While a route is running, show the running view in place of the grid.
+            if let Some(started_at) = state.running_since {
+                if !running_view_drawn || state.needs_full_redraw {
+                    Self::draw_running_view(
+                        &mut display,
+                        theme,
+                        &state.routes[state.selection].name,
+                    );
+                    running_view_drawn = true;
+                    timer_drawn_at = None;
+                    state.needs_full_redraw = false;
+                }
 
-                            Self::draw_item(
-                                &mut display,
-                                &theme,
-                                &state.routes[touch_index].name,
-                                touch_index,
-                                touch_index == state.selection,
-                                true,
-                            );
+                if timer_drawn_at
+                    .is_none_or(|drawn_at| drawn_at.elapsed() >= TIMER_REFRESH_INTERVAL)
+                {
+                    Self::draw_running_timer(&mut display, theme, started_at.elapsed());
+                    timer_drawn_at = Some(Instant::now());
+                }
+
+                active_item = None;
+                drop(state);
+
+                display.render();
+                sleep(Display::REFRESH_INTERVAL).await;
+                continue;
+            } else if running_view_drawn {
+                running_view_drawn = false;
+                state.needs_full_redraw = true;
+            }
+            let mut selection_changed = false;
 
-                            active_item = Some(touch_index);
-                        } else if let Some(old_active_item) = active_item {
-                            if old_active_item != touch_index {
-                                Self::draw_item(
-                                    &mut display,
-                                    &theme,
-                                    &state.routes[old_active_item].name,
-                                    old_active_item,
-                                    old_active_item == state.selection,
-                                    false,
-                                );
-
-                                active_item = None;
+            // While locked, touches are ignored entirely, so anything they were in the middle of
+            // is cancelled as if the touch was released somewhere else.
+            let locked = state.locked;
+            let theme = if locked { &locked_theme } else { theme };
+
+            if locked && detail_modal.take().is_some() {
+                confirming = None;
+                context_menu = None;
+                state.needs_full_redraw = true;
+            }
+
+            let touch = display.touch_status();
+            let touching = !locked && matches!(touch.state, TouchState::Held | TouchState::Pressed);
+
+            #[cfg(feature = "debug-logging")]
+            if let Some(logger) = &state.touch_logger
+                && touch.state != TouchState::Released
+            {
+                logger(touch.point.x, touch.point.y, touch.state);
+            }
+
+            // While the detail modal is open, a tap anywhere closes it. When confirming a
+            // selection, only a tap that starts and ends on the confirm button selects the
+            // route, and a tap anywhere else cancels. The context menu works the same way, with
+            // an option only chosen by a tap that starts and ends on it.
+            if let Some(modal) = detail_modal {
+                detail_modal = match (modal, touching) {
+                    (DetailModal::Opening, false) => Some(DetailModal::Open),
+                    (DetailModal::Open, true) => {
+                        confirm_pressed = Self::is_on_confirm_button(touch.point);
+                        menu_pressed = context_menu.and_then(|menu| menu.option_at(touch.point));
+                        Some(DetailModal::Closing)
+                    }
+                    (DetailModal::Closing, false) => {
+                        let chosen = context_menu.take().and_then(|menu| {
+                            menu_pressed
+                                .take()
+                                .filter(|&option| menu.option_at(touch.point) == Some(option))
+                                .map(|option| (menu.index(), option))
+                        });
+                        let selected = match chosen {
+                            Some((index, ContextMenuOption::Select))
+                                if state.routes[index].is_selectable() =>
+                            {
+                                Some(index)
                             }
+                            _ => confirming.take().filter(|_| {
+                                confirm_pressed && Self::is_on_confirm_button(touch.point)
+                            }),
+                        };
+
+                        if let Some(index) = selected {
+                            selection_changed = state.selection != index;
+                            state.dirty_selection = Some(state.selection);
+                            state.selection = index;
+                            state.pending_confirm = None;
                         }
-                    } else if let Some(prev_active_item) = active_item {
-                        if touch_index == prev_active_item && touch_index < N {
-                            let old_selection = state.selection;
 
-                            Self::draw_item(
+                        if let Some((index, ContextMenuOption::Details)) = chosen {
+                            Self::draw_detail_modal(
                                 &mut display,
-                                &theme,
-                                &state.routes[old_selection].name,
-                                old_selection,
-                                false,
+                                &state.routes[index],
+                                theme,
                                 false,
                             );
+                            Some(DetailModal::Open)
+                        } else {
+                            state.needs_full_redraw = true;
+                            None
+                        }
+                    }
+                    _ => Some(modal),
+                };
 
-                            Self::draw_item(
-                                &mut display,
-                                &theme,
-                                &state.routes[prev_active_item].name,
-                                prev_active_item,
-                                true,
-                                false,
-                            );
+                if detail_modal.is_some() {
+                    drop(state);
+                    display.render();
+                    sleep(Display::REFRESH_INTERVAL).await;
+                    continue;
+                }
+            }
 
-                            state.selection = prev_active_item;
-                            active_item = None;
-                        } else {
-                            Self::draw_item(
-                                &mut display,
-                                &theme,
-                                &state.routes[prev_active_item].name,
-                                prev_active_item,
-                                false,
-                                false,
-                            );
+            // In the list layout, dragging scrolls the list. A touch only counts as a drag
+            // once it has moved far enough, and from then on it can't select anything.
+            if !touching {
+                drag = None;
+            } else if metrics.list {
+                let drag = drag.get_or_insert(Drag {
+                    start_y: touch.point.y,
+                    start_scroll: metrics.page_number,
+                    scrolling: false,
+                });
+                let distance = touch.point.y - drag.start_y;
+
+                if !drag.scrolling && distance.abs() >= DRAG_THRESHOLD {
+                    drag.scrolling = true;
+                    state.long_press = None;
+                    state.needs_full_redraw |= active_item.take().is_some();
+                }
 
+                if drag.scrolling {
+                    let rows = (distance / metrics.row_height) as isize;
+                    let scroll = drag.start_scroll.saturating_add_signed(-rows);
+
+                    if scroll.min(metrics.page_count() - 1) != metrics.page_number {
+                        metrics.turn_to(scroll);
+                        state.needs_full_redraw = true;
+                    }
+                }
+            }
+
+            let scrolling = drag.is_some_and(|drag| drag.scrolling);
+            let touch_slot = metrics
+                .index_at(touch.point)
+                .filter(|_| !scrolling && !locked);
+            let touch_index = touch_slot
+                .and_then(|slot| metrics.route_at(slot))
+                .unwrap_or(N);
+
+            // Open a category's page, return to the first page, or turn the page once the
+            // button is tapped.
+            let touch_button = metrics
+                .button_at(touch.point)
+                .filter(|_| !scrolling && !locked);
+
+            if touching {
+                pressed_button = touch_button;
+            } else if let Some(button) = pressed_button.take()
+                && touch_button == Some(button)
+            {
+                match button {
+                    Slot::Group(category) => metrics.show_page(&state.routes, Some(category)),
+                    Slot::Back => metrics.show_page(&state.routes, None),
+                    Slot::PreviousPage => metrics.previous_page(),
+                    Slot::NextPage => metrics.next_page(),
+                    Slot::Route(_) | Slot::Empty => {}
+                }
+
+                state.needs_full_redraw = true;
+                active_item = None;
+            }
+
+            // Open the context menu once a route has been held down long enough.
+            if touching && touch_index < N {
+                match state.long_press {
+                    Some(long_press) if long_press.index == touch_index => {
+                        if long_press.started_at.elapsed() >= long_press_threshold {
+                            let menu = ContextMenu::new(touch_index);
+                            menu.render(&mut display, theme, &state.routes[touch_index]);
+
+                            context_menu = Some(menu);
+                            detail_modal = Some(DetailModal::Opening);
+                            state.long_press = None;
                             active_item = None;
+
+                            drop(state);
+                            display.render();
+                            sleep(Display::REFRESH_INTERVAL).await;
+                            continue;
                         }
                     }
+                    _ => {
+                        state.long_press = Some(LongPress {
+                            started_at: Instant::now(),
+                            index: touch_index,
+                        });
+                    }
+                }
+            } else {
+                state.long_press = None;
+            }
 
-                    if let Some(dirty_selection) = state.dirty_selection {
+            if touching {
+                if active_item.is_none_or(|prev_active_item| prev_active_item != touch_index)
+                    && touch_index < N
+                    && state.routes[touch_index].is_selectable()
+                {
+                    if let Some(old_active_item) = active_item {
                         Self::draw_item(
                             &mut display,
-                            &theme,
-                            &state.routes[dirty_selection].name,
-                            dirty_selection,
-                            false,
-                            false,
+                            theme,
+                            &metrics,
+                            &mut transitions,
+                            &state.routes,
+                            old_active_item,
+                            old_active_item == state.selection,
+                            state.is_pending(old_active_item),
                         );
+                    }
+
+                    Self::draw_item(
+                        &mut display,
+                        theme,
+                        &metrics,
+                        &mut transitions,
+                        &state.routes,
+                        touch_index,
+                        touch_index == state.selection,
+                        true,
+                    );
+
+                    active_item = Some(touch_index);
+                } else if let Some(old_active_item) = active_item
+                    && old_active_item != touch_index
+                {
+                    Self::draw_item(
+                        &mut display,
+                        theme,
+                        &metrics,
+                        &mut transitions,
+                        &state.routes,
+                        old_active_item,
+                        old_active_item == state.selection,
+                        state.is_pending(old_active_item),
+                    );
 
+                    active_item = None;
+                }
+            } else if let Some(prev_active_item) = active_item {
+                if touch_index == prev_active_item && touch_index < N && confirmation_modal {
+                    // Ask before selecting the route, in case the tap was accidental.
+                    Self::draw_detail_modal(
+                        &mut display,
+                        &state.routes[prev_active_item],
+                        theme,
+                        true,
+                    );
+
+                    detail_modal = Some(DetailModal::Opening);
+                    confirming = Some(prev_active_item);
+                    active_item = None;
+
+                    drop(state);
+                    display.render();
+                    sleep(Display::REFRESH_INTERVAL).await;
+                    continue;
+                } else if touch_index == prev_active_item
+                    && touch_index < N
+                    && require_confirmation
+                    && !state.is_pending(prev_active_item)
+                {
+                    // First tap of a confirmation, keep the route highlighted until
+                    // it's tapped again or the confirmation times out.
+                    if let Some((old_pending, _)) = state.pending_confirm {
                         Self::draw_item(
                             &mut display,
-                            &theme,
-                            &state.routes[state.selection].name,
-                            state.selection,
-                            true,
+                            theme,
+                            &metrics,
+                            &mut transitions,
+                            &state.routes,
+                            old_pending,
+                            old_pending == state.selection,
                             false,
                         );
+                    }
+
+                    state.pending_confirm = Some((prev_active_item, Instant::now()));
+                    active_item = None;
+                } else if touch_index == prev_active_item && touch_index < N {
+                    let old_selection = state.selection;
+                    state.pending_confirm = None;
 
-                        state.dirty_selection = None;
+                    Self::draw_item(
+                        &mut display,
+                        theme,
+                        &metrics,
+                        &mut transitions,
+                        &state.routes,
+                        old_selection,
+                        false,
+                        false,
+                    );
+
+                    Self::draw_item(
+                        &mut display,
+                        theme,
+                        &metrics,
+                        &mut transitions,
+                        &state.routes,
+                        prev_active_item,
+                        true,
+                        false,
+                    );
+
+                    state.selection = prev_active_item;
+                    selection_changed = old_selection != prev_active_item;
+                    active_item = None;
+
+                    // The previous selection was in a category on another page, whose
+                    // cell is highlighted.
+                    if metrics.slot_of(old_selection).is_none() {
+                        state.needs_full_redraw = true;
                     }
+                } else {
+                    Self::draw_item(
+                        &mut display,
+                        theme,
+                        &metrics,
+                        &mut transitions,
+                        &state.routes,
+                        prev_active_item,
+                        prev_active_item == state.selection,
+                        state.is_pending(prev_active_item),
+                    );
 
-                    drop(state);
-                    sleep(Display::REFRESH_INTERVAL).await;
+                    active_item = None;
+                }
+            }
+
+            // Revert the highlight on an unconfirmed route once it times out.
+            if let Some((pending, tapped_at)) = state.pending_confirm
+                && tapped_at.elapsed() > confirmation_timeout
+            {
+                state.pending_confirm = None;
+
+                if active_item != Some(pending) {
+                    Self::draw_item(
+                        &mut display,
+                        theme,
+                        &metrics,
+                        &mut transitions,
+                        &state.routes,
+                        pending,
+                        pending == state.selection,
+                        false,
+                    );
+                }
+            }
+
+            if let Some(controller) = controller {
+                let controller_state = controller.borrow().state().unwrap_or_default();
+                let selection = state.selection;
+
+                let new_selection = if controller_state.button_up.is_now_pressed() {
+                    state.cycled(false)
+                } else if controller_state.button_down.is_now_pressed() {
+                    state.cycled(true)
+                } else if controller_state.button_left.is_now_pressed() {
+                    metrics
+                        .slot_of(selection)
+                        .filter(|&slot| slot >= metrics.rows)
+                        .and_then(|slot| metrics.route_at(slot - metrics.rows))
+                } else if controller_state.button_right.is_now_pressed() {
+                    metrics
+                        .slot_of(selection)
+                        .and_then(|slot| metrics.route_at(slot + metrics.rows))
+                } else {
+                    None
+                };
+
+                if let Some(new_selection) =
+                    new_selection.filter(|&i| i != selection && state.routes[i].is_selectable())
+                {
+                    state.dirty_selection = Some(selection);
+                    state.selection = new_selection;
+                    selection_changed = true;
+                }
+
+                if searchable {
+                    // Pushing the joystick scrolls by one character, and it must be
+                    // released before it scrolls again.
+                    let stick = controller_state.left_stick.y();
+                    let scrolled = !search_stick_held && stick.abs() >= SEARCH_STICK_THRESHOLD;
+                    search_stick_held = stick.abs() >= SEARCH_STICK_THRESHOLD;
+
+                    if scrolled {
+                        search_character = if stick > 0.0 {
+                            (search_character + 1) % SEARCH_CHARACTERS.len()
+                        } else {
+                            (search_character + SEARCH_CHARACTERS.len() - 1)
+                                % SEARCH_CHARACTERS.len()
+                        };
+                    }
+
+                    let query_changed = if controller_state.button_a.is_now_pressed()
+                        && state.filter_query.len() < MAX_SEARCH_LEN
+                    {
+                        state
+                            .filter_query
+                            .push(SEARCH_CHARACTERS[search_character] as char);
+                        true
+                    } else {
+                        controller_state.button_b.is_now_pressed()
+                            && state.filter_query.pop().is_some()
+                    };
+
+                    if query_changed {
+                        selection_changed |= state.apply_filter();
+                    } else if scrolled {
+                        Self::draw_search_row(
+                            &mut display,
+                            theme,
+                            &metrics,
+                            &state.filter_query,
+                            SEARCH_CHARACTERS[search_character] as char,
+                        );
+                    }
+                }
+            }
+
+            // Lay out the current page again if the filtered routes changed, going back to
+            // the first page if nothing on this one is shown anymore.
+            if state.needs_reflow {
+                let page_number = metrics.page_number;
+                let category = metrics.category.filter(|&category| {
+                    state
+                        .routes
+                        .iter()
+                        .any(|route| route.matches_filter && route.category == Some(category))
+                });
+
+                metrics.show_page(&state.routes, category);
+                metrics.turn_to(page_number.min(metrics.page_count() - 1));
+                state.needs_reflow = false;
+                state.needs_full_redraw = true;
+            }
+
+            // Open the page containing the selected route if it was selected from
+            // elsewhere while a different page was shown.
+            if let Some(old_selection) = state.dirty_selection {
+                if metrics.slot_of(state.selection).is_none() {
+                    metrics.show_route(&state.routes, state.selection);
+                    state.needs_full_redraw = true;
+                } else if metrics.slot_of(old_selection).is_none() {
+                    state.needs_full_redraw = true;
+                }
+            }
+
+            if state.needs_full_redraw {
+                Self::draw_all(
+                    &mut display,
+                    theme,
+                    &metrics,
+                    &mut transitions,
+                    header,
+                    &state,
+                    active_item,
+                );
+
+                if searchable {
+                    Self::draw_search_row(
+                        &mut display,
+                        theme,
+                        &metrics,
+                        &state.filter_query,
+                        SEARCH_CHARACTERS[search_character] as char,
+                    );
+                }
+
+                if show_route_count {
+                    Self::draw_route_count(&mut display, theme, &state.routes);
+                }
+
+                competition_status_drawn = None;
+                detail_panel_drawn = Some(state.selection);
+
+                state.needs_full_redraw = false;
+                state.dirty_selection = None;
+            }
+
+            Self::draw_transitions(
+                &mut display,
+                theme,
+                &metrics,
+                &mut transitions,
+                &state.routes,
+            );
+
+            if show_competition_status {
+                let status = competition::status();
+                let (connected, mode) = (status.is_connected(), status.mode());
+
+                // Restart the period timer whenever the mode changes.
+                let started_at = match competition_status {
+                    Some((last_connected, last_mode, started_at))
+                        if last_connected == connected && last_mode == mode =>
+                    {
+                        started_at
+                    }
+                    _ => Instant::now(),
+                };
+                competition_status = Some((connected, mode, started_at));
+
+                let elapsed = started_at.elapsed().as_secs();
+                let text = match mode {
+                    _ if !connected => "Disconnected".to_owned(),
+                    CompetitionMode::Disabled => "Disabled".to_owned(),
+                    CompetitionMode::Autonomous => {
+                        format!("Auton {}:{:02}", elapsed / 60, elapsed % 60)
+                    }
+                    CompetitionMode::Driver => {
+                        format!("Driver {}:{:02}", elapsed / 60, elapsed % 60)
+                    }
+                };
+
+                if competition_status_drawn.as_ref() != Some(&text) {
+                    Self::draw_competition_status(&mut display, theme, &text);
+                    competition_status_drawn = Some(text);
                 }
-            }),
+            }
+
+            if show_battery
+                && battery_drawn_at
+                    .is_none_or(|drawn_at| drawn_at.elapsed() >= BATTERY_REFRESH_INTERVAL)
+            {
+                Self::draw_battery(&mut display, theme);
+                battery_drawn_at = Some(Instant::now());
+            }
+
+            if let Some(dirty_selection) = state.dirty_selection {
+                Self::draw_item(
+                    &mut display,
+                    theme,
+                    &metrics,
+                    &mut transitions,
+                    &state.routes,
+                    dirty_selection,
+                    false,
+                    false,
+                );
+
+                Self::draw_item(
+                    &mut display,
+                    theme,
+                    &metrics,
+                    &mut transitions,
+                    &state.routes,
+                    state.selection,
+                    true,
+                    false,
+                );
+
+                state.dirty_selection = None;
+            }
+
+            // Keep the detail panel showing the selected route, however it was selected.
+            if metrics.detail && detail_panel_drawn != Some(state.selection) {
+                Self::draw_detail_panel(
+                    &mut display,
+                    theme,
+                    &metrics,
+                    &state.routes[state.selection],
+                );
+                detail_panel_drawn = Some(state.selection);
+            }
+
+            // Keep an error on top of anything drawn this frame until the selector is redrawn.
+            if let Some(error) = &state.error {
+                Self::draw_error_banner(&mut display, error);
+            }
+
+            drop(state);
+
+            if selection_changed {
+                Self::notify_selection_change(&shared);
+            }
+
+            display.render();
+            sleep(Display::REFRESH_INTERVAL).await;
         }
     }
 
     /// Programatically selects an autonomous route by index.
-    pub fn select(&mut self, index: usize) {
+    ///
+    /// Indices refer to the order routes are displayed in, after sorting by [priority].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SelectError::Unavailable`] if the route is [disabled] or its precondition does
+    /// not currently allow it to be selected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// [priority]: Route::priority
+    /// [disabled]: SimpleSelect::set_route_enabled
+    pub fn select(&mut self, index: usize) -> Result<(), SelectError> {
         assert!(index < N, "Invalid route selection index.");
         let mut state = self.state.borrow_mut();
+
+        if !state.routes[index].is_available() {
+            return Err(SelectError::Unavailable { index });
+        }
+
+        if state.selection == index {
+            return Ok(());
+        }
+
         state.dirty_selection = Some(state.selection);
         state.selection = index;
+        drop(state);
+
+        Self::notify_selection_change(&self.state);
+
+        Ok(())
     }
 
-    fn draw_item(
-        display: &mut Display,
-        theme: &SimpleSelectTheme,
-        label: &str,
-        index: usize,
-        selected: bool,
-        active: bool,
-    ) {
-        let (background_color, text_color) = match (selected, active) {
-            (false, false) => (theme.background_default, theme.text_default),
-            (false, true) => (theme.background_active, theme.text_active),
-            (true, false) => (theme.background_selected, theme.text_selected),
-            (true, true) => (theme.background_selected_active, theme.text_selected_active),
-        };
+    /// Selects the route with the given [ID](Route::id).
+    ///
+    /// Unlike indices, route IDs stay the same when routes are reordered.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SelectError::UnknownId`] if no route has the ID, or
+    /// [`SelectError::Unavailable`] if the route cannot currently be selected.
+    pub fn select_by_id(&mut self, id: RouteId) -> Result<(), SelectError> {
+        let index = self
+            .state
+            .borrow()
+            .routes
+            .iter()
+            .position(|route| route.id() == id)
+            .ok_or(SelectError::UnknownId(id))?;
 
-        display.fill(
-            &Rect::from_dimensions(
-                [
-                    if index <= 5 {
-                        0
-                    } else {
-                        Display::HORIZONTAL_RESOLUTION / 2
-                    },
-                    (index % 6) as i16 * 40,
-                ],
-                238,
-                38,
-            ),
-            background_color,
-        );
+        self.select(index)
+    }
 
-        display.draw_text(
-            &Text::from_string(
-                label,
-                Font::new(FontSize::MEDIUM, FontFamily::Proportional),
-                [
-                    if index <= 5 {
-                        8
-                    } else {
-                        Display::HORIZONTAL_RESOLUTION / 2 + 8
-                    },
-                    ((index % 6) as i16 * 40 + 6),
-                ],
-            ),
-            text_color,
-            None,
-        );
+    /// Selects the next route that can be selected, wrapping around to the first route after the
+    /// last one.
+    ///
+    /// Routes that are [disabled], don't meet their precondition, or are hidden by a filter are
+    /// skipped. This behaves like selecting the route on the touchscreen, so the display is
+    /// updated and the [selection change callback] runs. It is useful for changing the selection
+    /// with controller buttons when the touchscreen can't be used:
+    ///
+    /// ```ignore
+    /// impl SelectCompete for Robot {
+    ///     async fn disabled(&mut self) {
+    ///         loop {
+    ///             let state = self.controller.state().unwrap_or_default();
+    ///
+    ///             if state.button_right.is_now_pressed() {
+    ///                 self.selector.cycle_next();
+    ///             } else if state.button_left.is_now_pressed() {
+    ///                 self.selector.cycle_prev();
+    ///             }
+    ///
+    ///             sleep(Controller::UPDATE_INTERVAL).await;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [disabled]: SimpleSelect::set_route_enabled
+    /// [selection change callback]: SimpleSelect::on_select
+    pub fn cycle_next(&mut self) {
+        self.cycle(true);
     }
 
-    fn draw_borders(display: &mut Display, theme: &SimpleSelectTheme) {
-        // Vertical gridline
-        display.fill(
-            &Line::new(
-                [Display::HORIZONTAL_RESOLUTION / 2 - 1, 0],
-                [
-                    Display::HORIZONTAL_RESOLUTION / 2 - 1,
-                    Display::VERTICAL_RESOLUTION,
-                ],
-            ),
-            theme.border,
-        );
+    /// Selects the previous route that can be selected, wrapping around to the last route before
+    /// the first one.
+    ///
+    /// See [`SimpleSelect::cycle_next`] for more information.
+    pub fn cycle_prev(&mut self) {
+        self.cycle(false);
+    }
 
-        // Horizontal gridline
-        for n in 1..=5 {
-            display.fill(
-                &Line::new(
-                    [0, n * 40 - 1],
-                    [Display::HORIZONTAL_RESOLUTION, n * 40 - 1],
-                ),
-                theme.border,
-            );
+    fn cycle(&mut self, forward: bool) {
+        let mut state = self.state.borrow_mut();
+
+        if let Some(index) = state.cycled(forward) {
+            state.dirty_selection = Some(state.selection);
+            state.selection = index;
+            drop(state);
+
+            Self::notify_selection_change(&self.state);
         }
     }
-}
 
-impl<R, const N: usize> Selector<R> for SimpleSelect<R, N> {
-    async fn run(&self, robot: &mut R) {
-        {
-            let state = self.state.borrow();
-            (state.routes[state.selection].callback)(robot)
+    /// Returns an iterator over the routes in this selector, in the order they are displayed.
+    ///
+    /// Each item is a snapshot of the route taken as the iterator advances. The selector's state
+    /// is only borrowed while producing each item, so the selector keeps running normally while
+    /// the iterator is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// for route in selector.routes() {
+    ///     println!("{}: {}", route.index, route.name);
+    /// }
+    /// ```
+    ///
+    /// A reference to the selector can also be iterated over directly, as in
+    /// `for route in &selector`.
+    pub fn routes(&self) -> RouteIter<'_, R, N> {
+        RouteIter {
+            selector: self,
+            indices: 0..N,
         }
-        .await;
+    }
+
+    /// Registers a callback that runs with the new route index whenever the selection changes.
+    ///
+    /// This replaces any callback set through [`SimpleSelectBuilder::on_selection_change`]. The
+    /// callback runs once for each change, whether it was made on the touchscreen, with a
+    /// controller, or through methods such as [`SimpleSelect::select`]. The selector's state isn't
+    /// borrowed while it runs, so it is free to call back into the selector.
+    pub fn on_select(&mut self, callback: impl FnMut(usize) + 'static) {
+        self.state.borrow_mut().on_selection_change = Some(Box::new(callback));
+    }
+
+    /// Returns the index of the currently selected route.
+    ///
+    /// This and the other getters for the selection only borrow the selector's state for the
+    /// duration of the call. The background task that draws the selector never holds onto its
+    /// borrow across an `await`, so they are safe to call at any time, such as from
+    /// [`SelectCompete::disabled`] to show the selection on a controller.
+    ///
+    /// [`SelectCompete::disabled`]: crate::compete::SelectCompete::disabled
+    pub fn selected_index(&self) -> usize {
+        self.state.borrow().selection
+    }
+
+    /// Returns the name of the currently selected route.
+    pub fn selected_name(&self) -> Cow<'static, str> {
+        let state = self.state.borrow();
+        state.routes[state.selection].name.clone()
+    }
+
+    /// Returns a copy of the currently selected route.
+    ///
+    /// The route is cloned rather than borrowed, so that holding onto it doesn't block the
+    /// selector's background task. Cloning a route doesn't copy the function it runs.
+    pub fn selected_route(&self) -> Route<R> {
+        let state = self.state.borrow();
+        state.routes[state.selection].clone()
+    }
+
+    /// Returns the number of routes in the selector.
+    pub const fn route_count(&self) -> usize {
+        N
+    }
+
+    /// Returns a serializable [descriptor](RouteDescriptor) for the currently selected route.
+    #[cfg(feature = "serde")]
+    pub fn selected_descriptor(&self) -> RouteDescriptor {
+        let state = self.state.borrow();
+        state.routes[state.selection].descriptor(state.selection)
+    }
+
+    /// Selects the route described by a [`RouteDescriptor`].
+    ///
+    /// The route is looked up by name rather than index, so descriptors saved before routes were
+    /// reordered still select the same route. Returns `true` if a route with the descriptor's
+    /// name exists and could be selected.
+    #[cfg(feature = "serde")]
+    pub fn select_from_descriptor(&mut self, descriptor: &RouteDescriptor) -> bool {
+        let index = self
+            .state
+            .borrow()
+            .routes
+            .iter()
+            .position(|route| route.name == descriptor.name);
+
+        index.is_some_and(|index| self.select(index).is_ok())
+    }
+
+    /// Returns `true` if the [default route] is currently selected.
+    ///
+    /// [default route]: Route::is_default
+    pub fn is_default_selected(&self) -> bool {
+        let state = self.state.borrow();
+        Self::default_index(&state.routes) == Some(state.selection)
+    }
+
+    /// Returns the index of the [default route] if it can be selected, or the first route that
+    /// can be selected otherwise.
+    ///
+    /// [default route]: Route::is_default
+    fn default_index(routes: &[Route<R>]) -> Option<usize> {
+        routes
+            .iter()
+            .position(|route| route.is_default && route.is_available())
+            .or_else(|| routes.iter().position(Route::is_available))
+    }
+
+    /// Returns the [ID](Route::id) of the currently selected route.
+    pub fn selected_id(&self) -> RouteId {
+        let state = self.state.borrow();
+        state.routes[state.selection].id()
+    }
+
+    /// Enables or disables a route at runtime.
+    ///
+    /// Disabled routes are drawn using the theme's disabled colors and cannot be selected. This
+    /// is useful for ruling out routes that rely on a broken mechanism without re-uploading the
+    /// program. If the currently selected route is disabled, the selection moves to the
+    /// [default route](Route::is_default), or the first route that can still be selected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set_route_enabled(&mut self, index: usize, enabled: bool) {
+        assert!(index < N, "Invalid route index.");
+        let mut state = self.state.borrow_mut();
+
+        state.routes[index].enabled = enabled;
+        state.needs_full_redraw = true;
+
+        if state.selection == index
+            && !enabled
+            && let Some(default) = Self::default_index(&state.routes)
+        {
+            state.selection = default;
+            drop(state);
+
+            Self::notify_selection_change(&self.state);
+        }
+    }
+
+    /// Hides every route that doesn't match `filter` from the grid.
+    ///
+    /// The remaining routes are moved up to fill the gaps left by hidden routes. The selected
+    /// route stays selected if it matches the filter, and otherwise the selection moves to the
+    /// first route that is still shown. Setting a filter replaces any previous one, and the
+    /// original layout is restored with [`SimpleSelect::clear_filter`].
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// selector.set_filter(|route| route.tags.contains(&"awp"));
+    /// ```
+    pub fn set_filter(&mut self, filter: impl Fn(&Route<R>) -> bool + 'static) {
+        self.replace_filter(Some(Box::new(filter)));
+    }
+
+    /// Hides every route that doesn't have the given [tag](Route::tags).
+    ///
+    /// This is a shorthand for [`SimpleSelect::set_filter`] using [`Route::has_tag`].
+    pub fn filter_by_tag(&mut self, tag: &str) {
+        let tag = tag.to_owned();
+        self.set_filter(move |route| route.has_tag(&tag));
+    }
+
+    /// Removes the filter set by [`SimpleSelect::set_filter`], showing every route again.
+    pub fn clear_filter(&mut self) {
+        self.replace_filter(None);
+    }
+
+    fn replace_filter(&mut self, filter: Option<RouteFilter<R>>) {
+        let mut state = self.state.borrow_mut();
+
+        state.filter = filter;
+
+        if state.apply_filter() {
+            drop(state);
+            Self::notify_selection_change(&self.state);
+        }
+    }
+
+    /// Replaces the route grid with a view showing the selected route and how long it has been
+    /// running.
+    ///
+    /// This is called automatically when a route is [run] unless disabled through
+    /// [`SimpleSelectBuilder::running_view`]. The grid is shown again once the route finishes or
+    /// [`SimpleSelect::hide_running_view`] is called.
+    ///
+    /// [run]: Selector::run
+    pub fn show_running_view(&self) {
+        self.state.borrow_mut().running_since = Some(Instant::now());
+    }
+
+    /// Returns from the running view to the route grid.
+    pub fn hide_running_view(&self) {
+        self.state.borrow_mut().running_since = None;
+    }
+
+    /// Stops drawing the selector to the display and responding to input.
+    ///
+    /// The selection is kept, and routes can still be run. This is useful for drawing something
+    /// else to the display, such as a diagnostic overlay. Rendering can be resumed with
+    /// [`SimpleSelect::start_rendering`].
+    pub fn stop_rendering(&mut self) {
+        self.task.borrow_mut().take();
+    }
+
+    /// Resumes drawing the selector to a display after [`SimpleSelect::stop_rendering`].
+    ///
+    /// If the selector is already being drawn, the previous rendering task is stopped first. The
+    /// whole interface is redrawn, starting from the first page.
+    pub fn start_rendering(&mut self, display: Display) {
+        let mut task = self.task.borrow_mut();
+        task.take();
+        *task = Some(task::spawn(Self::render(
+            self.state.clone(),
+            self.config.clone(),
+            display,
+        )));
+    }
+
+    /// Repaints the entire selector UI on the next display refresh.
+    ///
+    /// This should be called after drawing to the display from outside of the selector (for
+    /// example, a diagnostic overlay or debugging text drawn by a route) to restore the
+    /// selector's interface. The background, borders and every route are drawn again from the
+    /// current state, so the selection, page and [lock](SimpleSelect::set_locked) are kept.
+    ///
+    /// [`SelectCompete`](crate::compete::SelectCompete) calls this automatically when the
+    /// autonomous and driver control periods end.
+    pub fn redraw(&self) {
+        let mut state = self.state.borrow_mut();
+        state.needs_full_redraw = true;
+        state.error = None;
+    }
+
+    /// Swaps the selector's routes and selection with `routes` and `selection`, so that one
+    /// selector can switch between several sets of routes on the same display.
+    ///
+    /// `routes` should already have been [prepared](Self::prepare_routes).
+    fn swap_routes(&self, routes: &mut [Route<R>; N], selection: &mut usize) {
+        let mut state = self.state.borrow_mut();
+
+        std::mem::swap(&mut state.routes, routes);
+        std::mem::swap(&mut state.selection, selection);
+        state.pending_confirm = None;
+        state.long_press = None;
+        state.dirty_selection = None;
+        state.needs_full_redraw = true;
+        state.apply_filter();
+        drop(state);
+
+        Self::notify_selection_change(&self.state);
+    }
+
+    /// Locks or unlocks the selector's touchscreen.
+    ///
+    /// While locked, the selector is drawn slightly faded, a padlock is shown in the
+    /// [header](SimpleSelectBuilder::header) if there is one, and all touches are ignored.
+    /// Locking cancels any touch in progress, and closes the route context menu or detail modal
+    /// if either is open. The selection can still be changed through [`SimpleSelect::select`]
+    /// and the controller.
+    ///
+    /// This is useful for preventing accidental selections while the robot is being handled,
+    /// such as when referees plug in field control cables.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// impl SelectCompete for Robot {
+    ///     async fn connected(&mut self) {
+    ///         self.selector.set_locked(true);
+    ///     }
+    ///
+    ///     async fn disconnected(&mut self) {
+    ///         self.selector.set_locked(false);
+    ///     }
+    /// }
+    /// ```
+    pub fn set_locked(&self, locked: bool) {
+        let mut state = self.state.borrow_mut();
+
+        if state.locked != locked {
+            state.locked = locked;
+            state.needs_full_redraw = true;
+        }
+    }
+
+    /// Returns `true` if the touchscreen is [locked](SimpleSelect::set_locked).
+    pub fn is_locked(&self) -> bool {
+        self.state.borrow().locked
+    }
+
+    /// Persists the current selection and runs the user's selection change callback.
+    ///
+    /// The callback is temporarily taken out of the state, so it is free to call back into the
+    /// selector without causing a borrow panic.
+    fn notify_selection_change(state: &RefCell<SelectorState<R, N>>) {
+        let mut state_ref = state.borrow_mut();
+        let selection = state_ref.selection;
+        state_ref.last_changed = Some(Instant::now());
+        state_ref.controller_mirror = ControllerMirror::Stale;
+        let name = state_ref.routes[selection].name.clone();
+        let id = state_ref.routes[selection].id();
+        let telemetry = state_ref.telemetry.clone();
+
+        if let Some(path) = &state_ref.persist_to {
+            _ = std::fs::write(path, id.get().to_string());
+        }
+
+        if state_ref.mirror_to_serial {
+            Self::print_routes(&state_ref.routes, selection);
+        }
+
+        let callback = state_ref.on_selection_change.take();
+        drop(state_ref);
+
+        if let Some(telemetry) = telemetry {
+            telemetry.selection_changed(selection, &name);
+        }
+
+        // Put the callback back, unless it replaced itself while running.
+        if let Some(mut callback) = callback {
+            callback(selection);
+            state
+                .borrow_mut()
+                .on_selection_change
+                .get_or_insert(callback);
+        }
+    }
+
+    /// Prints a numbered list of routes over serial, with the selected route highlighted.
+    fn print_routes(routes: &[Route<R>], selection: usize) {
+        // Build the whole list first, so that it isn't interleaved with other output.
+        let mut list = String::from("[autons] routes:\n");
+
+        for (i, route) in routes.iter().enumerate() {
+            let (marker, style) = if i == selection {
+                ('>', "\x1b[1;32m")
+            } else if route.is_available() {
+                (' ', "")
+            } else {
+                (' ', "\x1b[2m")
+            };
+
+            list.push_str(&format!("{style}{marker} {i:>2}. {}\x1b[0m\n", route.name));
+        }
+
+        print!("{list}");
+    }
+
+    fn draw_all(
+        display: &mut Display,
+        theme: &SimpleSelectTheme,
+        metrics: &GridMetrics,
+        transitions: &mut Transitions<N>,
+        header: Option<&str>,
+        state: &SelectorState<R, N>,
+        active_item: Option<usize>,
+    ) {
+        transitions.reset();
+
+        // Background
+        display.fill(
+            &Rect::new(
+                [0, 0],
+                [Display::HORIZONTAL_RESOLUTION, Display::VERTICAL_RESOLUTION],
+            ),
+            theme.background_default,
+        );
+
+        if let Some(background) = metrics.background {
+            background.draw(
+                display,
+                [0, 0],
+                Rect::new(
+                    [0, 0],
+                    [Display::HORIZONTAL_RESOLUTION, Display::VERTICAL_RESOLUTION],
+                ),
+            );
+        }
+
+        if let Some(header) = header {
+            display.fill(
+                &Rect::new([0, 0], [Display::HORIZONTAL_RESOLUTION, HEADER_HEIGHT - 1]),
+                theme.background_selected,
+            );
+
+            display.draw_text(
+                &Text::from_string_aligned(
+                    header,
+                    Font::new(FontSize::SMALL, FontFamily::Proportional),
+                    [8, HEADER_HEIGHT / 2],
+                    Alignment::Start,
+                    Alignment::Center,
+                ),
+                Color::WHITE,
+                None,
+            );
+
+            if state.locked {
+                Self::draw_padlock(display, [Display::HORIZONTAL_RESOLUTION - 16, 4]);
+            }
+        }
+
+        // Grid lines
+        Self::draw_borders(display, theme, metrics);
+
+        for i in 0..N {
+            Self::draw_item(
+                display,
+                theme,
+                metrics,
+                transitions,
+                &state.routes,
+                i,
+                i == state.selection,
+                active_item == Some(i) || state.is_pending(i),
+            );
+        }
+
+        // Page and scroll buttons are highlighted when the selected route is further along in
+        // that direction.
+        let direction = metrics.direction_of(state.selection);
+        let page_label = format!("Page {}/{}", metrics.page_number + 1, metrics.page_count());
+
+        for (slot, &contents) in metrics.slots.iter().enumerate() {
+            match contents {
+                Slot::Route(_) | Slot::Empty => {}
+                Slot::Group(category) => {
+                    let count = state
+                        .routes
+                        .iter()
+                        .filter(|route| route.category == Some(category))
+                        .count();
+
+                    Self::paint_group(
+                        display,
+                        theme,
+                        metrics,
+                        slot,
+                        category,
+                        &format!("{count} routes  >"),
+                        state.routes[state.selection].category == Some(category),
+                    );
+                }
+                Slot::Back => {
+                    Self::paint_group(
+                        display,
+                        theme,
+                        metrics,
+                        slot,
+                        "< Back",
+                        metrics.category.unwrap_or_default(),
+                        false,
+                    );
+                }
+                Slot::PreviousPage => {
+                    Self::paint_group(
+                        display,
+                        theme,
+                        metrics,
+                        slot,
+                        "< Previous",
+                        &page_label,
+                        direction == Some(Ordering::Less),
+                    );
+                }
+                Slot::NextPage => {
+                    Self::paint_group(
+                        display,
+                        theme,
+                        metrics,
+                        slot,
+                        "Next >",
+                        &page_label,
+                        direction == Some(Ordering::Greater),
+                    );
+                }
+            }
+        }
+
+        if metrics.list {
+            Self::draw_scroll_buttons(display, theme, metrics, direction);
+        }
+
+        if metrics.detail {
+            Self::draw_detail_panel(display, theme, metrics, &state.routes[state.selection]);
+        }
+    }
+
+    /// Draws the panel on the right half of the detail layout, showing the details of `route`.
+    ///
+    /// The panel stops short of the page buttons beneath it when the list spans several pages.
+    fn draw_detail_panel(
+        display: &mut Display,
+        theme: &SimpleSelectTheme,
+        metrics: &GridMetrics,
+        route: &Route<R>,
+    ) {
+        const LINE_CHARACTERS: usize = 30;
+        const LINE_HEIGHT: i16 = 16;
+
+        // Approximate width of a character in the small font, for wrapping beside the thumbnail.
+        const CHARACTER_WIDTH: i16 = 7;
+
+        // Names longer than this are drawn in a smaller font so they fit in the panel.
+        const LARGE_NAME_CHARACTERS: usize = 10;
+
+        let left = metrics.cell_width + metrics.slide[1];
+        if left >= Display::HORIZONTAL_RESOLUTION {
+            return;
+        }
+
+        let rows = if metrics.page_count() > 1 {
+            metrics.rows - 2
+        } else {
+            metrics.rows
+        };
+        let bottom = metrics.top + metrics.row_height * rows as i16 - 2;
+
+        metrics.fill(
+            display,
+            theme,
+            Rect::from_dimensions(
+                [left, metrics.top],
+                (Display::HORIZONTAL_RESOLUTION - left) as u16,
+                (bottom - metrics.top) as u16,
+            ),
+            theme.background_default,
+        );
+
+        let name_size = if route.name.chars().count() <= LARGE_NAME_CHARACTERS {
+            FontSize::LARGE
+        } else {
+            FontSize::MEDIUM
+        };
+
+        display.draw_text(
+            &Text::from_string(
+                route.name.as_ref(),
+                Font::new(name_size, FontFamily::Proportional),
+                [left + 12, metrics.top + 8],
+            ),
+            theme.text_selected,
+            None,
+        );
+
+        // Points and duration are listed along the bottom of the panel, if known.
+        let stats = [
+            route.points.map(|points| format!("Points: {points}")),
+            route
+                .duration
+                .map(|duration| format!("Duration: {:.1}s", duration.as_secs_f64())),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        let stats_top = bottom - 8 - LINE_HEIGHT * stats.len() as i16;
+
+        for (i, line) in stats.into_iter().enumerate() {
+            display.draw_text(
+                &Text::from_string(
+                    line,
+                    Font::new(FontSize::SMALL, FontFamily::Proportional),
+                    [left + 12, stats_top + i as i16 * LINE_HEIGHT],
+                ),
+                theme.text_default,
+                None,
+            );
+        }
+
+        // Wrap the description onto as many lines as fit between the name and the stats.
+        let description_top = metrics.top + 48;
+
+        // The thumbnail sits in the top-right corner beneath the name, with the description
+        // wrapped to its left. It is left out if it doesn't fit above the stats.
+        let mut line_characters = LINE_CHARACTERS;
+        if let Some(thumbnail) = route.thumbnail {
+            let [width, height] = [thumbnail.width() as i16, thumbnail.height() as i16];
+            let x = Display::HORIZONTAL_RESOLUTION - 12 - width;
+
+            if x >= left + 12 && description_top + height <= stats_top {
+                thumbnail.draw(
+                    display,
+                    [x, description_top],
+                    Rect::from_dimensions(
+                        [x, description_top],
+                        thumbnail.width(),
+                        thumbnail.height(),
+                    ),
+                );
+                line_characters = ((x - left - 20) / CHARACTER_WIDTH).max(1) as usize;
+            }
+        }
+
+        let lines = wrap_text(
+            route.description.unwrap_or("No description."),
+            line_characters,
+        );
+
+        for (i, line) in lines
+            .into_iter()
+            .take(((stats_top - description_top) / LINE_HEIGHT).max(0) as usize)
+            .enumerate()
+        {
+            display.draw_text(
+                &Text::from_string(
+                    line,
+                    Font::new(FontSize::SMALL, FontFamily::Proportional),
+                    [left + 12, description_top + i as i16 * LINE_HEIGHT],
+                ),
+                theme.text_default,
+                None,
+            );
+        }
+    }
+
+    /// Draws the buttons on the right edge of the list layout that scroll it up and down.
+    ///
+    /// A button is drawn in the selected colors when the selected route is hidden in its
+    /// direction, and in the disabled colors when the list can't scroll any further that way.
+    fn draw_scroll_buttons(
+        display: &mut Display,
+        theme: &SimpleSelectTheme,
+        metrics: &GridMetrics,
+        direction: Option<Ordering>,
+    ) {
+        let height = metrics.row_height * metrics.rows as i16 / 2;
+        let buttons = [
+            ("^", Ordering::Less, metrics.page_number > 0),
+            (
+                "v",
+                Ordering::Greater,
+                metrics.page_number + 1 < metrics.page_count(),
+            ),
+        ];
+
+        for (n, (label, button_direction, enabled)) in buttons.into_iter().enumerate() {
+            let y = metrics.top + n as i16 * height;
+            let (background_color, text_color) = if direction == Some(button_direction) {
+                (theme.background_selected, theme.text_selected)
+            } else if enabled {
+                (theme.background_default, theme.text_default)
+            } else {
+                (theme.background_disabled, theme.text_disabled)
+            };
+
+            metrics.fill(
+                display,
+                theme,
+                Rect::from_dimensions(
+                    [metrics.cell_width, y],
+                    GridMetrics::SCROLL_BUTTON_WIDTH as u16,
+                    height as u16 - 2,
+                ),
+                background_color,
+            );
+
+            display.draw_text(
+                &Text::from_string_aligned(
+                    label,
+                    Font::new(FontSize::LARGE, FontFamily::Proportional),
+                    [
+                        metrics.cell_width + GridMetrics::SCROLL_BUTTON_WIDTH / 2,
+                        y + height / 2,
+                    ],
+                    Alignment::Center,
+                    Alignment::Center,
+                ),
+                text_color,
+                None,
+            );
+        }
+    }
+
+    /// Paints a cell that opens a category's page or returns to the first page.
+    ///
+    /// Category cells containing the selected route are drawn in the selected colors.
+    fn paint_group(
+        display: &mut Display,
+        theme: &SimpleSelectTheme,
+        metrics: &GridMetrics,
+        slot: usize,
+        label: &str,
+        detail: &str,
+        selected: bool,
+    ) {
+        let [x, y] = metrics.cell_origin(slot);
+        let (background_color, text_color) = if selected {
+            (theme.background_selected, theme.text_selected)
+        } else {
+            (theme.background_default, theme.text_default)
+        };
+
+        metrics.fill(
+            display,
+            theme,
+            Rect::from_dimensions(
+                [x, y],
+                metrics.cell_width as u16 - 2,
+                metrics.row_height as u16 - 2,
+            ),
+            background_color,
+        );
+
+        display.fill(
+            &Rect::from_dimensions([x, y], 4, metrics.row_height as u16 - 2),
+            theme.background_group_header,
+        );
+
+        display.draw_text(
+            &Text::from_string(
+                label,
+                Font::new(FontSize::MEDIUM, FontFamily::Proportional),
+                [x + 12, y + 6],
+            ),
+            text_color,
+            None,
+        );
+
+        display.draw_text(
+            &Text::from_string_aligned(
+                detail,
+                Font::new(FontSize::SMALL, FontFamily::Proportional),
+                [x + metrics.cell_width - 8, y + metrics.row_height - 14],
+                Alignment::End,
+                Alignment::Center,
+            ),
+            text_color,
+            None,
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_item(
+        display: &mut Display,
+        theme: &SimpleSelectTheme,
+        metrics: &GridMetrics,
+        transitions: &mut Transitions<N>,
+        routes: &[Route<R>],
+        index: usize,
+        selected: bool,
+        active: bool,
+    ) {
+        let route = &routes[index];
+        let (background_color, text_color) = match (selected, active) {
+            _ if !route.is_selectable() => (theme.background_disabled, theme.text_disabled),
+            (false, false) => (theme.background_default, theme.text_default),
+            (false, true) => (theme.background_active, theme.text_active),
+            (true, false) => match route.accent_color {
+                Some(accent) => {
+                    let background = Color::lerp(accent, theme.background_default, ACCENT_DIM);
+                    (background, readable_text(theme.text_selected, background))
+                }
+                None => (theme.background_selected, theme.text_selected),
+            },
+            (true, true) => (theme.background_selected_active, theme.text_selected_active),
+        };
+
+        // Tint unselected routes by their alliance.
+        let background_color = match route.alliance.tint() {
+            Some(tint) if !selected && route.is_selectable() => {
+                Color::lerp(background_color, tint, ALLIANCE_TINT)
+            }
+            _ => background_color,
+        };
+
+        let background_color = transitions.begin(index, background_color, text_color);
+        Self::paint_item(
+            display,
+            theme,
+            metrics,
+            routes,
+            index,
+            background_color,
+            text_color,
+        );
+    }
+
+    /// Advances any in-progress background color transitions.
+    fn draw_transitions(
+        display: &mut Display,
+        theme: &SimpleSelectTheme,
+        metrics: &GridMetrics,
+        transitions: &mut Transitions<N>,
+        routes: &[Route<R>],
+    ) {
+        for index in 0..N {
+            let Some(transition) = transitions.active[index] else {
+                continue;
+            };
+
+            let t =
+                transition.started_at.elapsed().as_secs_f32() / transitions.duration.as_secs_f32();
+            let background_color = Color::lerp(transition.from, transition.to, t);
+
+            Self::paint_item(
+                display,
+                theme,
+                metrics,
+                routes,
+                index,
+                background_color,
+                transition.text,
+            );
+            transitions.displayed[index] = Some(background_color);
+
+            if t >= 1.0 {
+                transitions.active[index] = None;
+            }
+        }
+    }
+
+    fn paint_item(
+        display: &mut Display,
+        theme: &SimpleSelectTheme,
+        metrics: &GridMetrics,
+        routes: &[Route<R>],
+        index: usize,
+        background_color: Color,
+        text_color: Color,
+    ) {
+        let route = &routes[index];
+        let Some(slot) = metrics.slot_of(index) else {
+            return;
+        };
+        let [x, y] = metrics.cell_origin(slot);
+
+        metrics.fill(
+            display,
+            theme,
+            Rect::from_dimensions(
+                [x, y],
+                metrics.cell_width as u16 - 2,
+                metrics.row_height as u16 - 2,
+            ),
+            background_color,
+        );
+
+        if let Some(accent) = route.accent_color {
+            display.fill(
+                &Rect::from_dimensions([x, y], ACCENT_STRIPE_WIDTH, metrics.row_height as u16 - 2),
+                accent,
+            );
+        }
+
+        // Marker on the default route so it can be found quickly.
+        if route.is_default {
+            display.fill(
+                &Circle::new([x + 8, y + metrics.row_height - 8], 3),
+                text_color,
+            );
+        }
+
+        display.draw_text(
+            &Text::from_string(
+                route.name.as_ref(),
+                Font::new(FontSize::MEDIUM, FontFamily::Proportional),
+                [x + 8, y + 6],
+            ),
+            text_color,
+            None,
+        );
+
+        // Badge with the route's point value and duration, if either is known.
+        let badge = [
+            route.points.map(|points| format!("{points} pts")),
+            route
+                .duration
+                .map(|duration| format!("{:.1}s", duration.as_secs_f64())),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("  ");
+
+        if !badge.is_empty() {
+            display.draw_text(
+                &Text::from_string_aligned(
+                    badge,
+                    Font::new(FontSize::SMALL, FontFamily::Proportional),
+                    [x + metrics.cell_width - 8, y + metrics.row_height - 14],
+                    Alignment::End,
+                    Alignment::Center,
+                ),
+                text_color,
+                None,
+            );
+        }
+
+        // Category header and separator on the first route of each category, unless categories
+        // already have their own pages.
+        if let Some(category) = route.category
+            && !metrics.grouped
+            && (index == 0 || routes[index - 1].category != route.category)
+        {
+            display.fill(
+                &Rect::from_dimensions([x, y], metrics.cell_width as u16 - 2, 2),
+                theme.background_group_header,
+            );
+
+            display.draw_text(
+                &Text::from_string_aligned(
+                    category,
+                    Font::new(FontSize::EXTRA_SMALL, FontFamily::Proportional),
+                    [x + metrics.cell_width - 8, y + 4],
+                    Alignment::End,
+                    Alignment::Start,
+                ),
+                text_color,
+                None,
+            );
+        }
+    }
+
+    /// Draws the modal showing a route's details.
+    ///
+    /// When `confirming` is `true`, the modal has buttons for confirming or cancelling the route's
+    /// selection in place of a dismiss button.
+    fn draw_detail_modal(
+        display: &mut Display,
+        route: &Route<R>,
+        theme: &SimpleSelectTheme,
+        confirming: bool,
+    ) {
+        const LINE_CHARACTERS: usize = 44;
+
+        let panel = Rect::from_dimensions(
+            [MODAL_LEFT, MODAL_TOP],
+            MODAL_WIDTH as u16,
+            MODAL_HEIGHT as u16,
+        );
+        display.fill(&panel, theme.background_default);
+        display.stroke(&panel, theme.border);
+
+        display.draw_text(
+            &Text::from_string(
+                route.name.as_ref(),
+                Font::new(FontSize::MEDIUM, FontFamily::Proportional),
+                [MODAL_LEFT + 12, MODAL_TOP + 10],
+            ),
+            theme.text_selected,
+            None,
+        );
+
+        let status = if !route.enabled {
+            "Disabled"
+        } else if route.is_available() {
+            "Available"
+        } else {
+            "Precondition not met"
+        };
+
+        display.draw_text(
+            &Text::from_string(
+                match route.points {
+                    Some(points) => format!("{status} - {points} pts"),
+                    None => status.to_owned(),
+                },
+                Font::new(FontSize::SMALL, FontFamily::Proportional),
+                [MODAL_LEFT + 12, MODAL_TOP + 40],
+            ),
+            if route.is_available() {
+                theme.text_default
+            } else {
+                theme.text_disabled
+            },
+            None,
+        );
+
+        // Wrap the description onto as many lines as fit above the button.
+        let lines = wrap_text(
+            route.description.unwrap_or("No description."),
+            LINE_CHARACTERS,
+        );
+
+        for (i, line) in lines.into_iter().take(5).enumerate() {
+            display.draw_text(
+                &Text::from_string(
+                    line,
+                    Font::new(FontSize::SMALL, FontFamily::Proportional),
+                    [MODAL_LEFT + 12, MODAL_TOP + 64 + i as i16 * 16],
+                ),
+                theme.text_default,
+                None,
+            );
+        }
+
+        if confirming {
+            for (label, center, background_color, text_color) in [
+                (
+                    "Cancel",
+                    MODAL_CANCEL_CENTER,
+                    theme.background_active,
+                    theme.text_active,
+                ),
+                (
+                    "Confirm",
+                    MODAL_CONFIRM_CENTER,
+                    theme.background_selected,
+                    theme.text_selected,
+                ),
+            ] {
+                display.fill(
+                    &Rect::from_dimensions_centered(
+                        center,
+                        MODAL_BUTTON_WIDTH as u16,
+                        MODAL_BUTTON_HEIGHT as u16,
+                    ),
+                    background_color,
+                );
+                display.draw_text(
+                    &Text::from_string_aligned(
+                        label,
+                        Font::new(FontSize::SMALL, FontFamily::Proportional),
+                        center,
+                        Alignment::Center,
+                        Alignment::Center,
+                    ),
+                    text_color,
+                    None,
+                );
+            }
+
+            return;
+        }
+
+        // Dismiss button. Tapping anywhere closes the modal, but this makes that obvious.
+        let button = Rect::from_dimensions_centered(
+            [
+                Display::HORIZONTAL_RESOLUTION / 2,
+                MODAL_TOP + MODAL_HEIGHT - 22,
+            ],
+            80,
+            28,
+        );
+        display.fill(&button, theme.background_selected);
+        display.draw_text(
+            &Text::from_string_aligned(
+                "OK",
+                Font::new(FontSize::SMALL, FontFamily::Proportional),
+                [
+                    Display::HORIZONTAL_RESOLUTION / 2,
+                    MODAL_TOP + MODAL_HEIGHT - 22,
+                ],
+                Alignment::Center,
+                Alignment::Center,
+            ),
+            theme.text_selected,
+            None,
+        );
+    }
+
+    /// Returns `true` if `point` is on the confirm button of the detail modal.
+    const fn is_on_confirm_button(point: Point2<i16>) -> bool {
+        let [x, y] = MODAL_CONFIRM_CENTER;
+
+        (point.x - x).abs() <= MODAL_BUTTON_WIDTH / 2
+            && (point.y - y).abs() <= MODAL_BUTTON_HEIGHT / 2
+    }
+
+    fn draw_running_view(display: &mut Display, theme: &SimpleSelectTheme, name: &str) {
+        display.fill(
+            &Rect::new(
+                [0, 0],
+                [Display::HORIZONTAL_RESOLUTION, Display::VERTICAL_RESOLUTION],
+            ),
+            theme.background_default,
+        );
+
+        display.draw_text(
+            &Text::from_string_aligned(
+                name,
+                Font::new(FontSize::LARGE, FontFamily::Proportional),
+                [
+                    Display::HORIZONTAL_RESOLUTION / 2,
+                    Display::VERTICAL_RESOLUTION / 2 - 16,
+                ],
+                Alignment::Center,
+                Alignment::Center,
+            ),
+            theme.text_default,
+            None,
+        );
+    }
+
+    /// Draws a banner across the middle of the display explaining why no route ran.
+    fn draw_error_banner(display: &mut Display, message: &str) {
+        const BANNER_HEIGHT: i16 = 60;
+
+        let top = (Display::VERTICAL_RESOLUTION - BANNER_HEIGHT) / 2;
+
+        display.fill(
+            &Rect::from_dimensions(
+                [0, top],
+                Display::HORIZONTAL_RESOLUTION as u16,
+                BANNER_HEIGHT as u16,
+            ),
+            Color::new(200, 40, 40),
+        );
+
+        for (text, font_size, y) in [
+            ("Hardware check failed", FontSize::MEDIUM, top + 18),
+            (message, FontSize::SMALL, top + 42),
+        ] {
+            display.draw_text(
+                &Text::from_string_aligned(
+                    text,
+                    Font::new(font_size, FontFamily::Proportional),
+                    [Display::HORIZONTAL_RESOLUTION / 2, y],
+                    Alignment::Center,
+                    Alignment::Center,
+                ),
+                Color::WHITE,
+                None,
+            );
+        }
+    }
+
+    fn draw_running_timer(display: &mut Display, theme: &SimpleSelectTheme, elapsed: Duration) {
+        let center = [
+            Display::HORIZONTAL_RESOLUTION / 2,
+            Display::VERTICAL_RESOLUTION / 2 + 32,
+        ];
+
+        // Clear the previous reading.
+        display.fill(
+            &Rect::from_dimensions([center[0] - 60, center[1] - 12], 120, 24),
+            theme.background_default,
+        );
+
+        display.draw_text(
+            &Text::from_string_aligned(
+                format!("{:.1}s", elapsed.as_secs_f64()),
+                Font::new(FontSize::MEDIUM, FontFamily::Monospace),
+                center,
+                Alignment::Center,
+                Alignment::Center,
+            ),
+            theme.text_default,
+            None,
+        );
+    }
+
+    /// Draws the competition status strip in the middle of the footer.
+    fn draw_competition_status(display: &mut Display, theme: &SimpleSelectTheme, text: &str) {
+        let top = Display::VERTICAL_RESOLUTION - FOOTER_HEIGHT;
+
+        // Clear the previous status.
+        display.fill(
+            &Rect::new(
+                [Display::HORIZONTAL_RESOLUTION / 2 - 80, top],
+                [
+                    Display::HORIZONTAL_RESOLUTION / 2 + 80,
+                    Display::VERTICAL_RESOLUTION,
+                ],
+            ),
+            theme.background_default,
+        );
+
+        display.draw_text(
+            &Text::from_string_aligned(
+                text,
+                Font::new(FontSize::SMALL, FontFamily::Proportional),
+                [Display::HORIZONTAL_RESOLUTION / 2, top + FOOTER_HEIGHT / 2],
+                Alignment::Center,
+                Alignment::Center,
+            ),
+            theme.text_default,
+            None,
+        );
+    }
+
+    /// Draws the number of routes that can currently be selected in the bottom-left corner.
+    fn draw_route_count(display: &mut Display, theme: &SimpleSelectTheme, routes: &[Route<R>]) {
+        let available = routes.iter().filter(|route| route.is_selectable()).count();
+        let top = Display::VERTICAL_RESOLUTION - FOOTER_HEIGHT;
+
+        display.draw_text(
+            &Text::from_string_aligned(
+                format!("{available} / {N} routes"),
+                Font::new(FontSize::SMALL, FontFamily::Proportional),
+                [8, top + FOOTER_HEIGHT / 2],
+                Alignment::Start,
+                Alignment::Center,
+            ),
+            Color::lerp(
+                theme.text_default,
+                theme.background_default,
+                SECONDARY_TEXT_DIM,
+            ),
+            None,
+        );
+    }
+
+    fn draw_battery(display: &mut Display, theme: &SimpleSelectTheme) {
+        let capacity = vexide::battery::capacity().clamp(0.0, 1.0);
+        let color = if capacity > 0.5 {
+            Color::GREEN
+        } else if capacity >= 0.2 {
+            Color::YELLOW
+        } else {
+            Color::RED
+        };
+
+        let top = Display::VERTICAL_RESOLUTION - FOOTER_HEIGHT;
+        let left = Display::HORIZONTAL_RESOLUTION - 124;
+
+        // Clear the previous reading.
+        display.fill(
+            &Rect::new(
+                [left - 4, top],
+                [Display::HORIZONTAL_RESOLUTION, Display::VERTICAL_RESOLUTION],
+            ),
+            theme.background_default,
+        );
+
+        // Battery icon, filled in proportion to the remaining capacity.
+        display.stroke(
+            &Rect::from_dimensions([left, top + 5], 24, 10),
+            theme.text_default,
+        );
+        display.fill(
+            &Rect::from_dimensions([left + 24, top + 8], 2, 4),
+            theme.text_default,
+        );
+        display.fill(
+            &Rect::from_dimensions([left + 2, top + 7], (capacity * 20.0).round() as u16, 6),
+            color,
+        );
+
+        display.draw_text(
+            &Text::from_string_aligned(
+                format!(
+                    "{:.1} V  {:.0}%",
+                    vexide::battery::voltage(),
+                    capacity * 100.0
+                ),
+                Font::new(FontSize::SMALL, FontFamily::Proportional),
+                [Display::HORIZONTAL_RESOLUTION - 8, top + FOOTER_HEIGHT / 2],
+                Alignment::End,
+                Alignment::Center,
+            ),
+            theme.text_default,
+            None,
+        );
+    }
+
+    /// Draws the search row directly above the route grid, showing the current query followed by
+    /// the character that will be added next.
+    fn draw_search_row(
+        display: &mut Display,
+        theme: &SimpleSelectTheme,
+        metrics: &GridMetrics,
+        query: &str,
+        next_character: char,
+    ) {
+        let top = metrics.top - SEARCH_ROW_HEIGHT;
+
+        display.fill(
+            &Rect::new([0, top], [Display::HORIZONTAL_RESOLUTION, metrics.top - 2]),
+            theme.background_active,
+        );
+
+        display.draw_text(
+            &Text::from_string_aligned(
+                format!("Search: {query}[{next_character}]"),
+                Font::new(FontSize::SMALL, FontFamily::Monospace),
+                [8, top + SEARCH_ROW_HEIGHT / 2],
+                Alignment::Start,
+                Alignment::Center,
+            ),
+            theme.text_active,
+            None,
+        );
+
+        display.draw_text(
+            &Text::from_string_aligned(
+                "A: Add  B: Delete",
+                Font::new(FontSize::EXTRA_SMALL, FontFamily::Proportional),
+                [
+                    Display::HORIZONTAL_RESOLUTION - 8,
+                    top + SEARCH_ROW_HEIGHT / 2,
+                ],
+                Alignment::End,
+                Alignment::Center,
+            ),
+            theme.text_active,
+            None,
+        );
+    }
+
+    /// Draws a 10x12 pixel padlock with its top-left corner at `origin`.
+    fn draw_padlock(display: &mut Display, origin: [i16; 2]) {
+        let [x, y] = origin;
+
+        // Shackle, with its lower half hidden behind the body.
+        display.stroke(&Circle::new([x + 5, y + 4], 3), Color::WHITE);
+        display.fill(&Rect::new([x, y + 5], [x + 10, y + 12]), Color::WHITE);
+    }
+
+    fn draw_borders(display: &mut Display, theme: &SimpleSelectTheme, metrics: &GridMetrics) {
+        let bottom = metrics.top + metrics.row_height * metrics.rows as i16;
+
+        // Vertical gridlines between columns, or the one separating the scroll buttons in the list
+        // layout
+        let vertical_lines = if metrics.list { 1 } else { metrics.columns - 1 };
+        for n in 1..=vertical_lines as i16 {
+            let x = n * metrics.cell_width - 1;
+            Self::draw_border_line(display, theme, [x, metrics.top], [x, bottom]);
+        }
+
+        // Horizontal gridlines, which stop short of the scroll buttons in the list layout and the
+        // panel in the detail layout
+        for n in 0..metrics.rows as i16 {
+            let y = metrics.top + n * metrics.row_height - 1;
+            let end = if (metrics.list || metrics.detail) && n > 0 {
+                metrics.cell_width - 1
+            } else {
+                Display::HORIZONTAL_RESOLUTION
+            };
+
+            if y >= 0 {
+                Self::draw_border_line(display, theme, [0, y], [end, y]);
+            }
+        }
+
+        // Gridline between the scroll buttons
+        if metrics.list {
+            let y = (metrics.top + bottom) / 2 - 1;
+            Self::draw_border_line(
+                display,
+                theme,
+                [metrics.cell_width, y],
+                [Display::HORIZONTAL_RESOLUTION, y],
+            );
+        }
+    }
+
+    /// Draws a horizontal or vertical gridline using the theme's border width and style.
+    ///
+    /// Lines thicker than one pixel grow upwards or leftwards from the given line, so that they
+    /// stay clear of the cell below or to the right of it.
+    fn draw_border_line(
+        display: &mut Display,
+        theme: &SimpleSelectTheme,
+        start: [i16; 2],
+        end: [i16; 2],
+    ) {
+        let horizontal = start[1] == end[1];
+        let length = if horizontal {
+            end[0] - start[0]
+        } else {
+            end[1] - start[1]
+        };
+
+        let (segment, gap) = match theme.border_style {
+            BorderStyle::Solid => (length + 1, 0),
+            BorderStyle::Dashed { segment, gap } => (
+                i16::try_from(segment.max(1)).unwrap_or(i16::MAX),
+                i16::try_from(gap).unwrap_or(i16::MAX),
+            ),
+        };
+
+        for offset in 0..i16::try_from(theme.border_width).unwrap_or(i16::MAX) {
+            let mut position = 0;
+
+            while position <= length {
+                let segment_end = position.saturating_add(segment - 1).min(length);
+                let (from, to) = if horizontal {
+                    (
+                        [start[0] + position, start[1] - offset],
+                        [start[0] + segment_end, start[1] - offset],
+                    )
+                } else {
+                    (
+                        [start[0] - offset, start[1] + position],
+                        [start[0] - offset, start[1] + segment_end],
+                    )
+                };
+
+                display.fill(&Line::new(from, to), theme.border);
+                position = segment_end.saturating_add(1).saturating_add(gap);
+            }
+        }
+    }
+}
+
+impl<R> SimpleSelect<R, 1> {
+    /// Creates a new selector with only one route.
+    ///
+    /// This is equivalent to `SimpleSelect::new(display, [route])`, but reads more clearly in
+    /// programs that only have one route for now and still want the selector's interface.
+    pub fn with_single(display: Display, route: Route<R>) -> Self {
+        Self::new(display, [route])
+    }
+}
+
+/// Iterator over snapshots of the routes in a [`SimpleSelect`].
+///
+/// This is returned by [`SimpleSelect::routes`] and by iterating over a `&SimpleSelect`.
+pub struct RouteIter<'a, R: 'static, const N: usize> {
+    selector: &'a SimpleSelect<R, N>,
+    indices: Range<usize>,
+}
+
+impl<R, const N: usize> Iterator for RouteIter<'_, R, N> {
+    type Item = RouteRef;
+
+    fn next(&mut self) -> Option<RouteRef> {
+        let index = self.indices.next()?;
+        let state = self.selector.state.borrow();
+        let route = &state.routes[index];
+
+        Some(RouteRef {
+            index,
+            name: route.name.clone(),
+            available: route.is_available(),
+            selected: index == state.selection,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<R, const N: usize> ExactSizeIterator for RouteIter<'_, R, N> {}
+
+impl<'a, R, const N: usize> IntoIterator for &'a SimpleSelect<R, N> {
+    type Item = RouteRef;
+    type IntoIter = RouteIter<'a, R, N>;
+
+    fn into_iter(self) -> RouteIter<'a, R, N> {
+        self.routes()
+    }
+}
+
+/// Hides the running view when dropped, including when a route is interrupted.
+struct RunningViewGuard<'a, R: 'static, const N: usize>(&'a SimpleSelect<R, N>);
+
+impl<R, const N: usize> Drop for RunningViewGuard<'_, R, N> {
+    fn drop(&mut self) {
+        self.0.hide_running_view();
+    }
+}
+
+impl<R, const N: usize> Selector<R> for SimpleSelect<R, N> {
+    /// Runs the selected route.
+    ///
+    /// If the selected route isn't [available](Route::is_available), which happens when no route
+    /// could be selected at all, nothing is run.
+    async fn run(&self, robot: &mut R) {
+        let route = {
+            let state = self.state.borrow();
+            state.routes[state.selection].clone()
+        };
+
+        if !route.is_available() {
+            println!(
+                "[autons] warning: route \"{}\" is unavailable and was not run",
+                route.name,
+            );
+            self.state.borrow_mut().timed_out = false;
+            return;
+        }
+
+        // Leave the controller screen to the robot's own code from here on.
+        self.state.borrow_mut().controller_mirror = ControllerMirror::Clear;
+
+        let _running_view = self.running_view.then(|| {
+            self.show_running_view();
+            RunningViewGuard(self)
+        });
+
+        let timings = route.run(robot).await;
+        self.state.borrow_mut().timed_out = timings.timed_out;
+
+        if let Some(telemetry) = &self.telemetry
+            && (route.setup.is_some() || route.teardown.is_some())
+        {
+            telemetry.route_timings(timings);
+        }
+    }
+
+    fn telemetry(&self) -> Option<&dyn SelectorTelemetry> {
+        self.telemetry.as_deref()
+    }
+
+    fn status(&self) -> Option<&dyn SelectorStatus<R>> {
+        Some(self)
+    }
+
+    fn last_changed(&self) -> Option<Instant> {
+        self.state.borrow().last_changed
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        Some(self.state.borrow().selection)
+    }
+
+    fn name_of(&self, index: usize) -> Option<Cow<'static, str>> {
+        self.state
+            .borrow()
+            .routes
+            .get(index)
+            .map(|route| route.name.clone())
+    }
+
+    /// Selects the [default route](Route::is_default), or the first route that can currently be
+    /// selected if there is no default.
+    fn reset(&mut self) {
+        let mut state = self.state.borrow_mut();
+
+        if let Some(default) = Self::default_index(&state.routes)
+            && default != state.selection
+        {
+            state.dirty_selection = Some(state.selection);
+            state.selection = default;
+            drop(state);
+
+            Self::notify_selection_change(&self.state);
+        }
+    }
+}
+
+impl<R, const N: usize> SelectorStatus<R> for SimpleSelect<R, N> {
+    /// Returns `true` if the selected route is a no-op, or can't be run because it isn't
+    /// [available](Route::is_available).
+    fn is_noop(&self) -> bool {
+        let state = self.state.borrow();
+        let route = &state.routes[state.selection];
+        route.is_noop || !route.is_available()
+    }
+
+    fn timed_out(&self) -> bool {
+        self.state.borrow().timed_out
+    }
+
+    fn redraw(&self) {
+        SimpleSelect::redraw(self);
+    }
+
+    /// Prints the error, and shows it in a banner over the selector until it is next redrawn.
+    fn show_error(&self, message: &str) {
+        println!("[autons] hardware check failed: {message}");
+        self.state.borrow_mut().error = Some(message.to_owned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use vexide::{display::Display, peripherals::Peripherals};
+
+    use super::{Route, SimpleSelect, SimpleSelectBuilder};
+    use crate::{Selector, SelectorStatus};
+
+    fn routes() -> [Route<()>; 2] {
+        [
+            Route::new("Left", |_| Box::pin(async {})),
+            Route::new("Right", |_| Box::pin(async {})),
+        ]
+    }
+
+    fn display() -> Display {
+        // SAFETY: Host builds use the mock SDK, so tests can't conflict over the display.
+        unsafe { Peripherals::steal() }.display
+    }
+
+    fn selector() -> SimpleSelect<(), 2> {
+        SimpleSelect::new(display(), routes())
+    }
+
+    #[test]
+    fn clones_share_selection() {
+        let mut selector = selector();
+        let mut clone = selector.clone();
+
+        selector.select(1).unwrap();
+        assert_eq!(clone.selected_index(), 1);
+
+        clone.select(0).unwrap();
+        assert_eq!(selector.selected_index(), 0);
+    }
+
+    #[test]
+    fn builds_custom_grid() {
+        let selector = SimpleSelectBuilder::new(routes())
+            .grid::<1, 2>()
+            .build(display());
+
+        assert_eq!(selector.selected_index(), 0);
+    }
+
+    #[test]
+    #[should_panic = "SimpleSelect grid rows must be at least one pixel tall."]
+    fn rejects_rows_shorter_than_a_pixel() {
+        SimpleSelectBuilder::new(routes())
+            .grid::<240, 1>()
+            .header("Routes")
+            .build(display());
+    }
+
+    #[test]
+    fn unavailable_selection_does_not_run() {
+        let routes: [Route<bool>; 2] = [
+            Route::new("Left", |ran| Box::pin(async { *ran = true })).with_precondition(|| false),
+            Route::new("Right", |ran| Box::pin(async { *ran = true })).with_precondition(|| false),
+        ];
+        let selector = SimpleSelect::new(display(), routes);
+
+        assert!(selector.is_noop());
+
+        let mut ran = false;
+        {
+            let run = pin!(selector.run(&mut ran));
+            assert_eq!(
+                run.poll(&mut Context::from_waker(Waker::noop())),
+                Poll::Ready(())
+            );
+        }
+
+        assert!(!ran);
+    }
+
+    #[test]
+    fn allows_repeated_placeholders() {
+        let routes: [Route<()>; 3] = [
+            Route::new("Left", |_| Box::pin(async {})),
+            Route::disabled("TBD"),
+            Route::disabled("TBD"),
+        ];
+        let selector = SimpleSelect::new(display(), routes);
+
+        assert_eq!(selector.state.borrow().routes.len(), 3);
+    }
+
+    #[test]
+    fn error_is_shown_until_redraw() {
+        let selector = selector();
+
+        selector.show_error("IMU is disconnected");
+        assert_eq!(
+            selector.state.borrow().error.as_deref(),
+            Some("IMU is disconnected")
+        );
+
+        selector.redraw();
+        assert!(selector.state.borrow().error.is_none());
     }
 }
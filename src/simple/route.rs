@@ -1,34 +1,951 @@
-use std::{future::Future, pin::Pin};
+use std::{
+    borrow::Cow,
+    ffi::CStr,
+    fmt,
+    future::{Future, poll_fn},
+    pin::{Pin, pin},
+    rc::Rc,
+    sync::Arc,
+    task::Poll,
+    time::{Duration, Instant},
+};
+
+use vexide::{color::Color, time::sleep};
+
+use super::{Alliance, Thumbnail};
+use crate::{RouteTimings, cancel::RouteContext};
 
 type RouteFn<Shared> = for<'s> fn(&'s mut Shared) -> Pin<Box<dyn Future<Output = ()> + 's>>;
 
+type ContextRouteFn<Shared> =
+    for<'s> fn(&'s mut Shared, RouteContext) -> Pin<Box<dyn Future<Output = ()> + 's>>;
+
+type FallibleRouteFn<Shared, E> =
+    for<'s> fn(&'s mut Shared) -> Pin<Box<dyn Future<Output = Result<(), E>> + 's>>;
+
+type MirroredRouteFn<Shared> =
+    for<'s> fn(&'s mut Shared, Alliance) -> Pin<Box<dyn Future<Output = ()> + 's>>;
+
+/// A shared, type-erased route closure.
+///
+/// This is the callback type used by routes created with [`Route::from_closure`].
+pub type AsyncRouteFn<R> = Arc<dyn for<'s> Fn(&'s mut R) -> Pin<Box<dyn Future<Output = ()> + 's>>>;
+
+/// A stable identifier for a [`Route`].
+///
+/// Unlike a route's index, its ID does not change when routes are reordered, making it suitable
+/// for persisting a selection. By default, a route's ID is a hash of its name.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RouteId(u32);
+
+impl RouteId {
+    /// Creates a route ID from a number.
+    pub const fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    /// Creates a route ID by hashing a route name.
+    ///
+    /// This uses the 32-bit FNV-1a hash, which is stable across program versions.
+    pub const fn from_name(name: &str) -> Self {
+        let bytes = name.as_bytes();
+        let mut hash: u32 = 0x811c_9dc5;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            hash ^= bytes[i] as u32;
+            hash = hash.wrapping_mul(0x0100_0193);
+            i += 1;
+        }
+
+        Self(hash)
+    }
+
+    /// Returns the ID as a number.
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl fmt::Display for RouteId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#010x}", self.0)
+    }
+}
+
+/// A snapshot of a route configured in a [`SimpleSelect`].
+///
+/// These are returned by [`SimpleSelect::routes`].
+///
+/// [`SimpleSelect`]: crate::simple::SimpleSelect
+/// [`SimpleSelect::routes`]: crate::simple::SimpleSelect::routes
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RouteRef {
+    /// Index of the route in the selector.
+    pub index: usize,
+
+    /// Name of the route.
+    pub name: Cow<'static, str>,
+
+    /// Whether the route can currently be selected.
+    pub available: bool,
+
+    /// Whether the route is currently selected.
+    pub selected: bool,
+}
+
+/// The function a [`Route`] runs when it is selected.
+///
+/// Most routes are plain function pointers, which can be created in `const` contexts and don't
+/// require an allocation. Routes that need to capture state, such as configuration loaded at
+/// startup, can instead use a closure through [`Route::from_closure`].
+pub enum RouteCallback<R> {
+    /// A function pointer.
+    Fn(RouteFn<R>),
+
+    /// A reference-counted closure.
+    Closure(AsyncRouteFn<R>),
+
+    /// A function pointer that also takes the [context](RouteContext) of the running route.
+    WithContext(ContextRouteFn<R>),
+
+    /// A function pointer that also takes the alliance the route runs for.
+    Alliance(MirroredRouteFn<R>, Alliance),
+}
+
+impl<R> RouteCallback<R> {
+    /// Calls the callback, returning the route's future.
+    pub fn call<'s>(&self, robot: &'s mut R) -> Pin<Box<dyn Future<Output = ()> + 's>> {
+        match self {
+            Self::Fn(callback) => callback(robot),
+            Self::Closure(callback) => callback(robot),
+            Self::WithContext(callback) => callback(robot, RouteContext::current()),
+            Self::Alliance(callback, alliance) => callback(robot, *alliance),
+        }
+    }
+}
+
+impl<R> Clone for RouteCallback<R> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Fn(callback) => Self::Fn(*callback),
+            Self::Closure(callback) => Self::Closure(callback.clone()),
+            Self::WithContext(callback) => Self::WithContext(*callback),
+            Self::Alliance(callback, alliance) => Self::Alliance(*callback, *alliance),
+        }
+    }
+}
+
+impl<R> fmt::Debug for RouteCallback<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fn(callback) => f.debug_tuple("Fn").field(callback).finish(),
+            Self::Closure(_) => f.debug_tuple("Closure").finish_non_exhaustive(),
+            Self::WithContext(callback) => f.debug_tuple("WithContext").field(callback).finish(),
+            Self::Alliance(callback, alliance) => f
+                .debug_tuple("Alliance")
+                .field(callback)
+                .field(alliance)
+                .finish(),
+        }
+    }
+}
+
 /// Route entry for [`SimpleSelect`].
 ///
 /// These are provided to [`SimpleSelect`] in the form of an array passed to [`SimpleSelect`].
-/// Route entries contain a function pointer to the provided route function, as well as a human-readable
+/// Route entries contain a [callback] to the provided route function, as well as a human-readable
 /// name for the route that is displayed in the selector's UI.
 ///
 /// It's recommended to use the [`route!()`] macro to aid in creating instances of this struct.
+/// Metadata can also be attached using the `with_*` builder methods, which are `const` so that
+/// routes can be stored in a `const` or `static` array:
+///
+/// ```ignore
+/// const ROUTES: [Route<Robot>; 1] = [Route::new("Rush", |robot| Box::pin(Robot::rush(robot)))
+///     .with_description("Center goal rush.")
+///     .with_color(Color::RED)
+///     .with_category("Match")];
+/// ```
+///
+/// Metadata that doesn't have a field of its own can be attached as a value of any type with
+/// [`Route::with_metadata`]. Routes without it have `()` metadata, which is what [`SimpleSelect`]
+/// expects.
 ///
 /// [`SimpleSelect`]: crate::simple::SimpleSelect
+/// [callback]: RouteCallback
 #[derive(Debug)]
-pub struct Route<R> {
-    pub name: &'static str,
-    pub callback: RouteFn<R>,
+pub struct Route<R, M = ()> {
+    /// Name of the route, displayed in the selector's UI.
+    ///
+    /// This is usually a string literal, but can also be an owned [`String`] for names that are
+    /// generated at runtime. See [`Route::with_name`].
+    pub name: Cow<'static, str>,
+    pub callback: RouteCallback<R>,
+
+    /// Optional human-readable explanation of what the route does.
+    pub description: Option<&'static str>,
+
+    /// Optional color used to mark this route in the selector.
+    ///
+    /// A stripe in this color is drawn along the left edge of the route's cell, and a slightly
+    /// faded version is used in place of the theme's selection color when the route is selected.
+    /// If the theme's selected text color isn't readable on it, black or white text is used
+    /// instead. This is useful for color-coding routes by strategy.
+    pub accent_color: Option<Color>,
+
+    /// Optional check for whether the route can currently be selected.
+    ///
+    /// Routes whose precondition returns `false` are drawn in a disabled style and cannot be
+    /// picked from the touchscreen or controller. The precondition is checked whenever the route
+    /// is drawn or interacted with.
+    pub precondition: Option<fn() -> bool>,
+
+    /// Whether the route can be selected.
+    ///
+    /// Disabled routes are drawn in a disabled style and cannot be picked from the touchscreen or
+    /// controller. This can be changed while the selector is running using
+    /// [`SimpleSelect::set_route_enabled`]. Defaults to `true`.
+    ///
+    /// [`SimpleSelect::set_route_enabled`]: crate::simple::SimpleSelect::set_route_enabled
+    pub enabled: bool,
+
+    /// Optional name of the group this route belongs to, such as `"Match"` or `"Skills"`.
+    ///
+    /// [`SimpleSelect`] draws a separator and header above the first route of each category.
+    /// Routes in the same category should be placed next to each other in the route array.
+    /// Routes without a category are drawn normally.
+    ///
+    /// [`SimpleSelect`]: crate::simple::SimpleSelect
+    pub category: Option<&'static str>,
+
+    /// Display order of the route, with higher priorities shown first.
+    ///
+    /// [`SimpleSelect`] sorts its routes by descending priority when it is constructed. Routes
+    /// with equal priority keep the order they were given in. Defaults to `0`.
+    ///
+    /// [`SimpleSelect`]: crate::simple::SimpleSelect
+    pub priority: i32,
+
+    /// Optional estimate of how long the route takes to run.
+    ///
+    /// This is shown next to the route's name in [`SimpleSelect`], which also prints a warning
+    /// over serial if it is longer than the autonomous period (15 seconds, or 60 seconds for
+    /// routes in a `"Skills"` [category]).
+    ///
+    /// [`SimpleSelect`]: crate::simple::SimpleSelect
+    /// [category]: Route::category
+    pub duration: Option<Duration>,
+
+    /// Optional time limit for the route.
+    ///
+    /// If the route is still running once this much time has passed, the selector stops it by
+    /// dropping its future and prints a warning over serial. [`SelectCompete::after_route`] still
+    /// runs afterwards, and [`SelectorStatus::timed_out`] reports whether the route was stopped.
+    ///
+    /// [`SelectCompete::after_route`]: crate::compete::SelectCompete::after_route
+    /// [`SelectorStatus::timed_out`]: crate::SelectorStatus::timed_out
+    pub timeout: Option<Duration>,
+
+    /// Optional function that runs when the route is stopped for exceeding its
+    /// [timeout](Route::timeout).
+    ///
+    /// See [`Route::with_on_timeout`].
+    pub on_timeout: Option<fn(&mut R)>,
+
+    /// Optional explicit ID for the route, used in place of a hash of its name.
+    ///
+    /// See [`Route::id`].
+    pub id_override: Option<RouteId>,
+
+    /// Optional estimate of how many points the route scores.
+    ///
+    /// This is shown next to the route's name in [`SimpleSelect`].
+    ///
+    /// [`SimpleSelect`]: crate::simple::SimpleSelect
+    pub points: Option<u8>,
+
+    /// Optional image shown for the route, such as a field diagram of its path.
+    ///
+    /// This is drawn in the detail panel of [`SimpleSelectLayout::Detail`] when the route is
+    /// selected. Routes without one leave that part of the panel blank.
+    ///
+    /// [`SimpleSelectLayout::Detail`]: crate::simple::SimpleSelectLayout::Detail
+    pub thumbnail: Option<Thumbnail>,
+
+    /// Whether the route does nothing when run.
+    ///
+    /// This is set by [`Route::noop`], and allows [telemetry] to distinguish a deliberate no-op
+    /// from a real route.
+    ///
+    /// [telemetry]: crate::SelectorTelemetry::noop_route_ran
+    pub is_noop: bool,
+
+    /// Whether the route is a placeholder reserving a slot for a route that hasn't been written.
+    ///
+    /// This is set by [`Route::disabled`]. Placeholders can never be selected, so several of
+    /// them may share a name and [ID](Route::id) in one selector.
+    pub is_placeholder: bool,
+
+    /// Whether this is the route selected when the selector starts.
+    ///
+    /// At most one route in a selector may be the default. If none are, the first route that
+    /// can be selected is used instead.
+    pub is_default: bool,
+
+    /// The alliance or game mode this route is intended for.
+    ///
+    /// Defaults to [`Alliance::None`].
+    pub alliance: Alliance,
+
+    /// Free-form labels for grouping and filtering routes, such as `"skills"` or `"elims"`.
+    ///
+    /// See [`SimpleSelect::filter_by_tag`] for showing only routes with a given tag.
+    ///
+    /// [`SimpleSelect::filter_by_tag`]: crate::simple::SimpleSelect::filter_by_tag
+    pub tags: &'static [&'static str],
+
+    /// Whether the route matches the search query of a [searchable] [`SimpleSelect`].
+    ///
+    /// [searchable]: crate::simple::SimpleSelectBuilder::searchable
+    /// [`SimpleSelect`]: crate::simple::SimpleSelect
+    pub(crate) matches_filter: bool,
+
+    /// Optional function that runs before the route's main function.
+    ///
+    /// See [`Route::with_setup`].
+    pub setup: Option<RouteFn<R>>,
+
+    /// Optional function that runs after the route's main function.
+    ///
+    /// See [`Route::with_teardown`].
+    pub teardown: Option<RouteFn<R>>,
+
+    /// Custom metadata attached to the route.
+    ///
+    /// See [`Route::with_metadata`].
+    pub metadata: M,
 }
 
-impl<R> Clone for Route<R> {
+impl<R, M: Clone> Clone for Route<R, M> {
     fn clone(&self) -> Self {
         Self {
-            name: self.name,
-            callback: self.callback,
+            name: self.name.clone(),
+            callback: self.callback.clone(),
+            description: self.description,
+            accent_color: self.accent_color,
+            precondition: self.precondition,
+            enabled: self.enabled,
+            category: self.category,
+            priority: self.priority,
+            duration: self.duration,
+            timeout: self.timeout,
+            on_timeout: self.on_timeout,
+            id_override: self.id_override,
+            points: self.points,
+            thumbnail: self.thumbnail,
+            is_noop: self.is_noop,
+            is_placeholder: self.is_placeholder,
+            is_default: self.is_default,
+            alliance: self.alliance,
+            tags: self.tags,
+            matches_filter: self.matches_filter,
+            setup: self.setup,
+            teardown: self.teardown,
+            metadata: self.metadata.clone(),
         }
     }
 }
 
 impl<R> Route<R> {
     pub const fn new(name: &'static str, callback: RouteFn<R>) -> Self {
-        Self { name, callback }
+        Self::from_callback(name, RouteCallback::Fn(callback))
+    }
+
+    /// Creates a route whose function also takes the [context](RouteContext) of the running route.
+    ///
+    /// This is usually created through the [`route_ctx!`] macro.
+    ///
+    /// [`route_ctx!`]: crate::route_ctx
+    pub const fn new_with_context(name: &'static str, callback: ContextRouteFn<R>) -> Self {
+        Self::from_callback(name, RouteCallback::WithContext(callback))
+    }
+
+    const fn from_callback(name: &'static str, callback: RouteCallback<R>) -> Self {
+        Self::from_callback_with_metadata(name, callback, ())
+    }
+
+    const fn from_callback_with_metadata<M>(
+        name: &'static str,
+        callback: RouteCallback<R>,
+        metadata: M,
+    ) -> Route<R, M> {
+        Route {
+            name: Cow::Borrowed(name),
+            callback,
+            description: None,
+            accent_color: None,
+            precondition: None,
+            enabled: true,
+            category: None,
+            priority: 0,
+            duration: None,
+            timeout: None,
+            on_timeout: None,
+            id_override: None,
+            points: None,
+            thumbnail: None,
+            is_noop: false,
+            is_placeholder: false,
+            is_default: false,
+            alliance: Alliance::None,
+            tags: &[],
+            matches_filter: true,
+            setup: None,
+            teardown: None,
+            metadata,
+        }
+    }
+
+    /// Creates a route with custom [metadata](Route::metadata).
+    ///
+    /// This is equivalent to [`Route::new`] followed by [`Route::with_metadata`], but can be used
+    /// in `const` contexts.
+    pub const fn new_with_metadata<M>(
+        name: &'static str,
+        callback: RouteFn<R>,
+        metadata: M,
+    ) -> Route<R, M> {
+        Self::from_callback_with_metadata(name, RouteCallback::Fn(callback), metadata)
+    }
+
+    /// Creates a route that runs a closure.
+    ///
+    /// Unlike [`Route::new`], the closure may capture variables from its environment. This is
+    /// useful for routes that depend on data that isn't known until runtime, such as a path
+    /// loaded from the SD card.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let path = load_path("/usd/path.txt");
+    ///
+    /// let route = Route::from_closure("Follow Path", move |robot: &mut Robot| {
+    ///     let path = path.clone();
+    ///     Box::pin(async move { robot.follow(&path).await })
+    /// });
+    /// ```
+    pub fn from_closure(
+        name: impl Into<Cow<'static, str>>,
+        callback: impl for<'s> Fn(&'s mut R) -> Pin<Box<dyn Future<Output = ()> + 's>> + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            callback: RouteCallback::Closure(Arc::new(callback)),
+            ..Self::new("", |_| Box::pin(async {}))
+        }
+    }
+
+    /// Creates a route that runs an async closure.
+    ///
+    /// This works like [`Route::from_closure`], except that the closure is an `async` closure,
+    /// so its future doesn't need to be boxed by hand. Routes can usually be written more
+    /// concisely with [`compact_route!`].
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let route = Route::from_async_fn("Drive Forward", async |robot: &mut Robot| {
+    ///     robot.drive(100).await;
+    /// });
+    /// ```
+    pub fn from_async_fn(
+        name: impl Into<Cow<'static, str>>,
+        callback: impl AsyncFn(&mut R) + 'static,
+    ) -> Self {
+        let callback = Arc::new(callback);
+
+        Self::from_closure(name, move |robot| {
+            let callback = callback.clone();
+            Box::pin(async move { callback(robot).await })
+        })
+    }
+
+    /// Creates a route from a function that can fail.
+    ///
+    /// If the function returns an error, it is printed over serial along with the route's name
+    /// and how long it ran for. The route then completes normally, so the rest of the autonomous
+    /// period continues as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// impl Robot {
+    ///     async fn left(&mut self) -> Result<(), MotorError> {
+    ///         self.intake.set_voltage(12.0)?;
+    ///         // ...
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let route = Route::logged("Left", |robot| Box::pin(Robot::left(robot)));
+    /// ```
+    pub fn logged<E: fmt::Debug + 'static>(
+        name: impl Into<Cow<'static, str>>,
+        callback: FallibleRouteFn<R, E>,
+    ) -> Self
+    where
+        R: 'static,
+    {
+        let name = name.into();
+        let route_name = name.clone();
+
+        Self::from_closure(name, move |robot| {
+            let route_name = route_name.clone();
+
+            Box::pin(async move {
+                let start = Instant::now();
+
+                if let Err(error) = callback(robot).await {
+                    println!(
+                        "[autons] error: route \"{route_name}\" failed after {:.1}s: {error:?}",
+                        start.elapsed().as_secs_f64(),
+                    );
+                }
+            })
+        })
+    }
+
+    /// Creates a route that runs several routes back-to-back.
+    ///
+    /// Each route runs to completion before the next one starts, including its [setup] and
+    /// [teardown] functions. Each route's [timeout] applies to that route alone, so a route that
+    /// times out is stopped and the sequence moves on to the next one. While a route runs,
+    /// [`RouteContext::current`] reports its name and ID.
+    ///
+    /// Other metadata on the given routes, such as their preconditions, is ignored. The same
+    /// route may appear more than once. If a route panics, the routes after it do not run.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let skills = Route::sequence(
+    ///     "Skills",
+    ///     [
+    ///         route!("Left", Robot::left),
+    ///         route!("Cross", Robot::cross_field),
+    ///         route!("Left", Robot::left),
+    ///     ],
+    /// );
+    /// ```
+    ///
+    /// [setup]: Route::with_setup
+    /// [teardown]: Route::with_teardown
+    /// [timeout]: Route::timeout
+    pub fn sequence(
+        name: impl Into<Cow<'static, str>>,
+        routes: impl IntoIterator<Item = Route<R>>,
+    ) -> Self
+    where
+        R: 'static,
+    {
+        let routes: Rc<[Route<R>]> = routes.into_iter().collect();
+
+        Self::from_closure(name, move |robot| {
+            let routes = routes.clone();
+
+            Box::pin(async move {
+                for route in routes.iter() {
+                    route.run(robot).await;
+                }
+            })
+        })
+    }
+
+    /// Creates a pair of routes for the red and blue alliances from one function.
+    ///
+    /// This is useful for routes that are identical on both sides of the field except for being
+    /// mirrored. The function is passed the route's [`Alliance`], and `names` gives the names of
+    /// the red and blue routes, in that order. The pair doesn't allocate, so it can be used to
+    /// build a `const` array of routes.
+    ///
+    /// The [`route_mirrored!`] macro creates the pair from an async function, naming the routes
+    /// by appending `" Red"` and `" Blue"` to a base name.
+    ///
+    /// [`route_mirrored!`]: crate::simple::route_mirrored
+    pub const fn mirrored_pair(
+        names: [&'static str; 2],
+        callback: MirroredRouteFn<R>,
+    ) -> [Self; 2] {
+        let [red, blue] = names;
+
+        [
+            Self::from_callback(red, RouteCallback::Alliance(callback, Alliance::Red))
+                .with_alliance(Alliance::Red),
+            Self::from_callback(blue, RouteCallback::Alliance(callback, Alliance::Blue))
+                .with_alliance(Alliance::Blue),
+        ]
+    }
+
+    /// Creates a route whose name is given as a C string.
+    ///
+    /// Route names are normally provided as `&'static str`. This constructor is useful for names
+    /// that already exist as C strings, such as those shared with C code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not valid UTF-8.
+    pub const fn from_c_str(name: &'static CStr, callback: RouteFn<R>) -> Self {
+        let Ok(name) = name.to_str() else {
+            panic!("Route names must be valid UTF-8.");
+        };
+
+        Self::new(name, callback)
+    }
+
+    /// Creates a route that does nothing.
+    ///
+    /// This is useful as a safe fallback entry, for example when a partner's route would
+    /// conflict with any of your own. [`SelectCompete::before_route`] and
+    /// [`SelectCompete::after_route`] still run around it.
+    ///
+    /// [`SelectCompete::before_route`]: crate::compete::SelectCompete::before_route
+    /// [`SelectCompete::after_route`]: crate::compete::SelectCompete::after_route
+    pub const fn noop(name: &'static str) -> Self {
+        let mut route = Self::new(name, |_| Box::pin(async {}));
+        route.is_noop = true;
+        route
+    }
+
+    /// Creates a placeholder route that does nothing and can never be selected.
+    ///
+    /// This is useful for reserving a slot in the selector for a route that hasn't been written
+    /// yet, while making it clear in the UI that the slot is intentionally blank. The same
+    /// placeholder, such as `"TBD"`, may be used for several slots in one selector, even though
+    /// they share an [ID](Route::id).
+    pub const fn disabled(name: &'static str) -> Self {
+        let mut route = Self::new(name, |_| Box::pin(async {})).with_precondition(|| false);
+        route.is_placeholder = true;
+        route
+    }
+
+    /// Attaches custom [metadata](Route::metadata) to the route.
+    ///
+    /// This is useful for information specific to a team's own selector or strategy code,
+    /// without needing a dedicated field on every route:
+    ///
+    /// ```ignore
+    /// struct StrategyMeta {
+    ///     expected_score: u32,
+    ///     alliance: Alliance,
+    /// }
+    ///
+    /// let route = route!(Robot::rush).with_metadata(StrategyMeta {
+    ///     expected_score: 12,
+    ///     alliance: Alliance::Red,
+    /// });
+    /// ```
+    ///
+    /// Routes with metadata can't be shown in [`SimpleSelect`], which only accepts routes
+    /// without it.
+    ///
+    /// [`SimpleSelect`]: crate::simple::SimpleSelect
+    #[must_use]
+    pub fn with_metadata<M>(self, metadata: M) -> Route<R, M> {
+        let Self {
+            name,
+            callback,
+            description,
+            accent_color,
+            precondition,
+            enabled,
+            category,
+            priority,
+            duration,
+            timeout,
+            on_timeout,
+            id_override,
+            points,
+            thumbnail,
+            is_noop,
+            is_placeholder,
+            is_default,
+            alliance,
+            tags,
+            matches_filter,
+            setup,
+            teardown,
+            metadata: (),
+        } = self;
+
+        Route {
+            name,
+            callback,
+            description,
+            accent_color,
+            precondition,
+            enabled,
+            category,
+            priority,
+            duration,
+            timeout,
+            on_timeout,
+            id_override,
+            points,
+            thumbnail,
+            is_noop,
+            is_placeholder,
+            is_default,
+            alliance,
+            tags,
+            matches_filter,
+            setup,
+            teardown,
+            metadata,
+        }
+    }
+}
+
+impl<R, M> Route<R, M> {
+    /// Sets the route's [name](Route::name).
+    ///
+    /// Unlike [`Route::new`], this accepts owned strings, allowing for names that are generated
+    /// at runtime:
+    ///
+    /// ```ignore
+    /// let route = route!("", Robot::follow_path).with_name(format!("Path {file_stem}"));
+    /// ```
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the route's [description](Route::description).
+    #[must_use]
+    pub const fn with_description(mut self, description: &'static str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the route's [accent color](Route::accent_color).
+    #[must_use]
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.accent_color = Some(color);
+        self
+    }
+
+    /// Sets the route's [category](Route::category).
+    #[must_use]
+    pub const fn with_category(mut self, category: &'static str) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Sets the route's [precondition](Route::precondition).
+    #[must_use]
+    pub const fn with_precondition(mut self, precondition: fn() -> bool) -> Self {
+        self.precondition = Some(precondition);
+        self
+    }
+
+    /// Sets the route's [alliance](Route::alliance).
+    #[must_use]
+    pub const fn with_alliance(mut self, alliance: Alliance) -> Self {
+        self.alliance = alliance;
+        self
+    }
+
+    /// Sets the route's display [priority](Route::priority).
+    #[must_use]
+    pub const fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the route's estimated [duration](Route::duration).
+    #[must_use]
+    pub const fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets the route's [timeout](Route::timeout).
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets a function that runs as soon as the route is stopped for exceeding its
+    /// [timeout](Route::timeout).
+    ///
+    /// This is useful for stopping motors that the route left running, since the route is
+    /// stopped wherever it was last suspended. It runs before the route's [teardown], and has no
+    /// effect unless the route has a timeout.
+    ///
+    /// [teardown]: Route::with_teardown
+    #[must_use]
+    pub const fn with_on_timeout(mut self, on_timeout: fn(&mut R)) -> Self {
+        self.on_timeout = Some(on_timeout);
+        self
+    }
+
+    /// Sets a function that runs before the route's main function.
+    ///
+    /// This is useful for setup that only one route needs, such as shifting a PTO, which doesn't
+    /// belong in [`SelectCompete::before_route`]. The route's [timeout](Route::timeout) only
+    /// applies to its main function, not its setup.
+    ///
+    /// [`SelectCompete::before_route`]: crate::compete::SelectCompete::before_route
+    #[must_use]
+    pub const fn with_setup(mut self, setup: RouteFn<R>) -> Self {
+        self.setup = Some(setup);
+        self
+    }
+
+    /// Sets a function that runs after the route's main function.
+    ///
+    /// The teardown also runs if the main function is stopped for exceeding its
+    /// [timeout](Route::timeout). It does *not* run if the whole route is dropped, such as when the
+    /// competition mode changes; use a [`CancelToken`] handler for cleanup that must always happen.
+    ///
+    /// [`CancelToken`]: crate::cancel::CancelToken
+    #[must_use]
+    pub const fn with_teardown(mut self, teardown: RouteFn<R>) -> Self {
+        self.teardown = Some(teardown);
+        self
+    }
+
+    /// Sets the route's [tags](Route::tags).
+    #[must_use]
+    pub const fn with_tags(mut self, tags: &'static [&'static str]) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Returns `true` if the route has the given [tag](Route::tags).
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(&tag)
+    }
+
+    /// Sets the route's estimated [point value](Route::points).
+    #[must_use]
+    pub const fn with_points(mut self, points: u8) -> Self {
+        self.points = Some(points);
+        self
+    }
+
+    /// Sets the route's [thumbnail](Route::thumbnail).
+    #[must_use]
+    pub const fn with_thumbnail(mut self, thumbnail: Thumbnail) -> Self {
+        self.thumbnail = Some(thumbnail);
+        self
+    }
+
+    /// Sets whether this is the [default route](Route::is_default).
+    #[must_use]
+    pub const fn with_default(mut self, is_default: bool) -> Self {
+        self.is_default = is_default;
+        self
+    }
+
+    /// Sets an explicit [ID](Route::id) for the route.
+    ///
+    /// This is useful for keeping a persisted selection when a route is renamed, or for
+    /// distinguishing routes with the same name.
+    #[must_use]
+    pub const fn with_id(mut self, id: RouteId) -> Self {
+        self.id_override = Some(id);
+        self
+    }
+
+    /// Returns the route's stable identifier.
+    ///
+    /// This is the ID set with [`Route::with_id`] if there is one, or a hash of the route's name
+    /// otherwise. Every route given to a [`SimpleSelect`] must have a unique ID.
+    ///
+    /// [`SimpleSelect`]: crate::simple::SimpleSelect
+    pub fn id(&self) -> RouteId {
+        self.id_override
+            .unwrap_or_else(|| RouteId::from_name(&self.name))
+    }
+
+    /// Returns `true` if the route is enabled and its precondition allows it to be selected.
+    pub fn is_available(&self) -> bool {
+        self.enabled && self.precondition.is_none_or(|precondition| precondition())
+    }
+
+    /// Returns `true` if the route is [available](Route::is_available) and matches the current
+    /// search query, if any.
+    pub(crate) fn is_selectable(&self) -> bool {
+        self.matches_filter && self.is_available()
+    }
+
+    /// Runs the route's setup, main function, and teardown, stopping the main function if it
+    /// exceeds its [timeout](Route::timeout).
+    ///
+    /// Returns the time spent in each phase.
+    pub(crate) async fn run(&self, robot: &mut R) -> RouteTimings {
+        let _context = RouteContext::enter_route(self);
+        let mut timings = RouteTimings::default();
+
+        if let Some(setup) = self.setup {
+            let start = Instant::now();
+            setup(robot).await;
+            timings.setup = Some(start.elapsed());
+        }
+
+        let start = Instant::now();
+        timings.timed_out = self.run_body(robot).await;
+        timings.body = start.elapsed();
+
+        if let Some(teardown) = self.teardown {
+            let start = Instant::now();
+            teardown(robot).await;
+            timings.teardown = Some(start.elapsed());
+        }
+
+        timings
+    }
+
+    /// Runs the route's main function, stopping it if it exceeds its [timeout](Route::timeout).
+    ///
+    /// Returns `true` if the route was stopped because it timed out.
+    async fn run_body(&self, robot: &mut R) -> bool {
+        let mut route = self.callback.call(robot);
+
+        let Some(timeout) = self.timeout else {
+            route.await;
+            return false;
+        };
+
+        let mut deadline = pin!(sleep(timeout));
+        let timed_out = poll_fn(|cx| {
+            if route.as_mut().poll(cx).is_ready() {
+                Poll::Ready(false)
+            } else if deadline.as_mut().poll(cx).is_ready() {
+                Poll::Ready(true)
+            } else {
+                Poll::Pending
+            }
+        })
+        .await;
+        drop(route);
+
+        if timed_out {
+            println!(
+                "[autons] warning: route \"{}\" timed out after {:.1}s",
+                self.name,
+                timeout.as_secs_f64(),
+            );
+
+            if let Some(on_timeout) = self.on_timeout {
+                on_timeout(robot);
+            }
+        }
+
+        timed_out
     }
 }
 
@@ -42,13 +959,541 @@ impl<R> Route<R> {
 ///     route!("Route 2", Robot::route_2),
 /// ];
 /// ```
+///
+/// If only a function is provided, the last segment of its path is used as the route's name.
+/// For example, `route!(Robot::left_side)` creates a route named `left_side`. To use the full
+/// path as the name instead, prefix it with `@path`, as in `route!(@path Robot::left_side)`.
+///
+/// Routes that share a function with different parameters can be created using either a
+/// closure or by passing an extra argument after the robot:
+///
+/// ```ignore
+/// let routes = [
+///     route!("Rush Left", |robot| Robot::rush(robot, Side::Left)),
+///     route!("Rush Right", Robot::rush, Side::Right),
+/// ];
+/// ```
+///
+/// The extra argument is evaluated once when the route is created, so it may be a runtime value
+/// such as a tuned delay. It must be [`Clone`] and `'static`, since each run of the route is
+/// given its own clone.
+///
+/// Plain closures must not capture any variables from their environment, so that they can be
+/// stored as function pointers. `move` closures are instead stored using
+/// [`Route::from_closure`], and may capture values that are [`Copy`], such as a tuned delay:
+///
+/// ```ignore
+/// let delay = load_delay();
+///
+/// route!("Delayed Rush", move |robot| Robot::delayed_rush(robot, delay))
+/// ```
+///
+/// To pass more than one extra argument, use the closure form.
+///
+/// A description and [accent color] can also be provided:
+///
+/// ```ignore
+/// route!(
+///     "Rush",
+///     Robot::rush,
+///     "Aggressive route for the center goals.",
+///     Color::new(200, 50, 50)
+/// )
+/// ```
+///
+/// The route selected when the selector starts can be marked as the [default route]:
+///
+/// ```ignore
+/// route!("Safe AWP", Robot::safe_awp, default)
+/// ```
+///
+/// Routes can be given a [priority] to move them towards the start of the selector:
+///
+/// ```ignore
+/// route!("Solo AWP", Robot::solo_awp, priority = 10)
+/// ```
+///
+/// Additional metadata can also be given as a list of keyed values:
+///
+/// ```ignore
+/// route!("Rush", Robot::rush, color: Color::RED, description: "Center goal rush.")
+/// ```
+///
+/// The supported keys are:
+///
+/// - `description`: Sets [`Route::description`].
+/// - `color`: Sets the route's [accent color].
+/// - `category`: Sets the route's [`Route::category`].
+/// - `priority`: Sets the route's [priority].
+/// - `duration`: Sets the route's estimated [`Route::duration`].
+/// - `timeout`: Sets the route's [`Route::timeout`].
+/// - `on_timeout`: Sets a function that runs if the route times out, see
+///   [`Route::with_on_timeout`].
+/// - `setup`: Sets a function that runs before the route, see [`Route::with_setup`].
+/// - `teardown`: Sets a function that runs after the route, see [`Route::with_teardown`].
+/// - `alliance`: Sets the route's [`Route::alliance`].
+/// - `id`: Sets the route's [ID](Route::id) to the given number.
+/// - `default`: Sets whether the route is the [default route].
+/// - `points`: Sets the route's estimated [`Route::points`].
+/// - `thumbnail`: Sets the route's [`Route::thumbnail`].
+/// - `tags`: Sets the route's [`Route::tags`] from an array, such as `tags: ["skills", "red"]`.
+///
+/// [accent color]: Route::accent_color
+/// [priority]: Route::priority
+/// [default route]: Route::is_default
 #[macro_export]
 macro_rules! route {
-    ($func:path) => {{
-        ::autons::simple::Route::new(stringify!($func), |robot| {
-            ::std::boxed::Box::pin($func(robot))
+    (@path $first:ident $(:: $rest:ident)*) => {{
+        ::autons::simple::Route::new(
+            concat!(stringify!($first) $(, "::", stringify!($rest))*),
+            |robot| ::std::boxed::Box::pin($first $(:: $rest)*(robot)),
+        )
+    }};
+    ($($segment:ident)::+) => {{
+        ::autons::simple::Route::new(::autons::__route_name!($($segment)::+), |robot| {
+            ::std::boxed::Box::pin($($segment)::+(robot))
         })
     }};
+    ($name:expr, move |$robot:ident $(: $robot_ty:ty)?| $body:expr) => {{
+        ::autons::simple::Route::from_closure($name, move |$robot $(: $robot_ty)?| {
+            ::std::boxed::Box::pin($body)
+        })
+    }};
+    ($name:expr, |$robot:ident $(: $robot_ty:ty)?| $body:expr) => {{
+        ::autons::simple::Route::new($name, |$robot $(: $robot_ty)?| ::std::boxed::Box::pin($body))
+    }};
     ($name:expr, $func:path) => {{ ::autons::simple::Route::new($name, |robot| ::std::boxed::Box::pin($func(robot))) }};
+    ($name:expr, $func:path, default) => {{
+        ::autons::simple::Route::new($name, |robot| ::std::boxed::Box::pin($func(robot)))
+            .with_default(true)
+    }};
+    ($name:expr, $func:path, priority = $priority:expr) => {{
+        ::autons::simple::Route::new($name, |robot| ::std::boxed::Box::pin($func(robot)))
+            .with_priority($priority)
+    }};
+    ($name:expr, $func:path, $($key:ident : $value:expr),+ $(,)?) => {{
+        let route =
+            ::autons::simple::Route::new($name, |robot| ::std::boxed::Box::pin($func(robot)));
+        $(let route = ::autons::__route_field!(route, $key, $value);)+
+        route
+    }};
+    ($name:expr, $func:path, $arg:expr) => {{
+        let arg = $arg;
+        ::autons::simple::Route::from_closure($name, move |robot| {
+            ::std::boxed::Box::pin($func(robot, ::std::clone::Clone::clone(&arg)))
+        })
+    }};
+    ($name:expr, $func:path, $description:expr, $accent_color:expr) => {{
+        ::autons::simple::Route::new($name, |robot| ::std::boxed::Box::pin($func(robot)))
+            .with_description($description)
+            .with_color($accent_color)
+    }};
 }
 pub use route;
+
+/// Creates a [`Route`] whose function also takes the [context](RouteContext) of the running route.
+///
+/// This accepts the same forms as [`route!`], except that the route function takes a
+/// [`RouteContext`] as its second argument. The context carries the route's name, ID and alliance,
+/// the instant it started running, and its cancellation token.
+///
+/// # Examples
+///
+/// ```ignore
+/// impl Robot {
+///     async fn rush(&mut self, ctx: RouteContext) {
+///         if ctx.alliance() == Alliance::Red {
+///             // ...
+///         }
+///     }
+/// }
+///
+/// let routes = [
+///     route!(Robot::skills),
+///     route_ctx!(Robot::rush),
+///     route_ctx!("Safe Rush", Robot::rush, description: "Rush without crossing."),
+/// ];
+/// ```
+#[macro_export]
+macro_rules! route_ctx {
+    ($($segment:ident)::+) => {{
+        ::autons::simple::Route::new_with_context(
+            ::autons::__route_name!($($segment)::+),
+            |robot, ctx| ::std::boxed::Box::pin($($segment)::+(robot, ctx)),
+        )
+    }};
+    ($name:expr, $func:path) => {{
+        ::autons::simple::Route::new_with_context($name, |robot, ctx| {
+            ::std::boxed::Box::pin($func(robot, ctx))
+        })
+    }};
+    ($name:expr, $func:path, $($key:ident : $value:expr),+ $(,)?) => {{
+        let route = ::autons::simple::Route::new_with_context($name, |robot, ctx| {
+            ::std::boxed::Box::pin($func(robot, ctx))
+        });
+        $(let route = ::autons::__route_field!(route, $key, $value);)+
+        route
+    }};
+}
+pub use route_ctx;
+
+/// Creates a [`Route`] from an inline async block, without a separate route function.
+///
+/// The closure takes the robot and returns an `async` block, which is boxed by the macro. This
+/// is convenient for short routes in small projects. Like [`route!`], metadata can be given as
+/// keyed values after the closure. The closure may capture values from its environment by
+/// prefixing it with `move`. The robot's type usually can't be inferred, so it should be
+/// annotated, as in `|robot: &mut Robot|`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let routes = [
+///     compact_route!("Drive Forward", |robot: &mut Robot| async {
+///         robot.drive(100).await;
+///     }),
+///     compact_route!("Turn", |robot: &mut Robot| async { robot.turn(90.0).await }, color: Color::RED),
+/// ];
+/// ```
+///
+/// See [`Route::from_async_fn`] for more information.
+#[macro_export]
+macro_rules! compact_route {
+    ($name:expr, $(move)? |$robot:ident $(: $robot_ty:ty)?| $body:expr $(,)?) => {{
+        ::autons::simple::Route::from_async_fn($name, async move |$robot $(: $robot_ty)?| {
+            $body.await
+        })
+    }};
+    ($name:expr, $(move)? |$robot:ident $(: $robot_ty:ty)?| $body:expr, $($key:ident : $value:expr),+ $(,)?) => {{
+        let route = ::autons::compact_route!($name, |$robot $(: $robot_ty)?| $body);
+        $(let route = ::autons::__route_field!(route, $key, $value);)+
+        route
+    }};
+}
+pub use compact_route;
+
+/// Concisely creates an array of [`Route`]s.
+///
+/// Each entry is a route name followed by `=>` and the route function. Metadata supported by
+/// the keyed form of [`route!`] can be given in braces after the function. An entry whose
+/// function is `disabled` creates a [placeholder](Route::disabled) instead.
+///
+/// # Example
+///
+/// ```ignore
+/// let routes = routes![
+///     "Safe AWP" => Robot::safe_awp { default: true },
+///     "Rush" => Robot::rush { color: Color::RED, description: "Center goal rush." },
+///     "Skills" => Robot::skills,
+///     "TBD" => disabled,
+///     "TBD" => disabled,
+/// ];
+/// ```
+///
+/// Unlike individual [`route!`] calls, this checks the routes as a whole at compile time. It is
+/// an error for two routes other than placeholders to share a name, or for more than one route
+/// to be marked as the default.
+#[macro_export]
+macro_rules! routes {
+    ($($name:literal => $($func:ident)::+ $({ $($key:ident : $value:expr),* $(,)? })?),* $(,)?) => {{
+        const _: () = ::autons::simple::__check_routes(
+            &[$($name),*],
+            &[$(::autons::__route_is_placeholder!($($func)::+)),*],
+            &[$(false $($(|| ::autons::__route_is_default!($key, $value))*)?),*],
+        );
+
+        [$({
+            let route = ::autons::__routes_entry!($name, $($func)::+);
+            $($(let route = ::autons::__route_field!(route, $key, $value);)*)?
+            route
+        }),*]
+    }};
+}
+pub use routes;
+
+/// Panics with a message naming a route name that was given to [`routes!`] more than once.
+///
+/// Formatting arguments can't be used in `const` panics, so the message is assembled by hand.
+const fn duplicate_route_name(name: &str) -> ! {
+    const PREFIX: &[u8] = b"routes! contains more than one route named \"";
+    const SUFFIX: &[u8] = b"\".";
+
+    let mut message = [0; 128];
+    let mut len = 0;
+
+    let name = name.as_bytes();
+    let name_len = if name.len() > message.len() - PREFIX.len() - SUFFIX.len() {
+        message.len() - PREFIX.len() - SUFFIX.len()
+    } else {
+        name.len()
+    };
+
+    let mut i = 0;
+    while i < PREFIX.len() {
+        message[len] = PREFIX[i];
+        len += 1;
+        i += 1;
+    }
+
+    i = 0;
+    while i < name_len {
+        message[len] = name[i];
+        len += 1;
+        i += 1;
+    }
+
+    i = 0;
+    while i < SUFFIX.len() {
+        message[len] = SUFFIX[i];
+        len += 1;
+        i += 1;
+    }
+
+    match str::from_utf8(message.split_at(len).0) {
+        Ok(message) => panic!("{}", message),
+        // A very long name was cut off in the middle of a character.
+        Err(_) => panic!("routes! contains more than one route with the same name."),
+    }
+}
+
+/// Creates a single entry of [`routes!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __routes_entry {
+    ($name:literal, disabled) => {
+        ::autons::simple::Route::disabled($name)
+    };
+    ($name:literal, $($func:ident)::+) => {
+        ::autons::simple::Route::new($name, |robot| ::std::boxed::Box::pin($($func)::+(robot)))
+    };
+}
+
+/// Evaluates to `true` if a [`routes!`] entry is a placeholder.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __route_is_placeholder {
+    (disabled) => {
+        true
+    };
+    ($($func:ident)::+) => {
+        false
+    };
+}
+
+/// Checks the routes given to [`routes!`] at compile time.
+#[doc(hidden)]
+pub const fn __check_routes(names: &[&str], placeholders: &[bool], defaults: &[bool]) {
+    let mut i = 0;
+    let mut default_count = 0;
+
+    while i < names.len() {
+        let mut j = 0;
+
+        while j < i {
+            if placeholders[i] || placeholders[j] {
+                j += 1;
+                continue;
+            }
+
+            let (a, b) = (names[i].as_bytes(), names[j].as_bytes());
+            let mut k = 0;
+
+            while k < a.len() && k < b.len() && a[k] == b[k] {
+                k += 1;
+            }
+
+            if k == a.len() && k == b.len() {
+                duplicate_route_name(names[i]);
+            }
+
+            j += 1;
+        }
+
+        if defaults[i] {
+            default_count += 1;
+        }
+
+        i += 1;
+    }
+
+    if default_count > 1 {
+        panic!("routes! contains more than one route marked as the default.");
+    }
+}
+
+/// Evaluates to a keyed metadata value if its key is `default`, or `false` otherwise.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __route_is_default {
+    (default, $value:expr) => {
+        $value
+    };
+    ($key:ident, $value:expr) => {
+        false
+    };
+}
+
+/// Applies a keyed metadata field to a route for [`route!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __route_field {
+    ($route:ident, description, $value:expr) => {
+        $route.with_description($value)
+    };
+    ($route:ident, color, $value:expr) => {
+        $route.with_color($value)
+    };
+    ($route:ident, category, $value:expr) => {
+        $route.with_category($value)
+    };
+    ($route:ident, priority, $value:expr) => {
+        $route.with_priority($value)
+    };
+    ($route:ident, duration, $value:expr) => {
+        $route.with_duration($value)
+    };
+    ($route:ident, setup, $value:expr) => {
+        $route.with_setup(|robot| ::std::boxed::Box::pin($value(robot)))
+    };
+    ($route:ident, teardown, $value:expr) => {
+        $route.with_teardown(|robot| ::std::boxed::Box::pin($value(robot)))
+    };
+    ($route:ident, timeout, $value:expr) => {
+        $route.with_timeout($value)
+    };
+    ($route:ident, on_timeout, $value:expr) => {
+        $route.with_on_timeout($value)
+    };
+    ($route:ident, alliance, $value:expr) => {
+        $route.with_alliance($value)
+    };
+    ($route:ident, tags, $value:expr) => {
+        $route.with_tags(&$value)
+    };
+    ($route:ident, points, $value:expr) => {
+        $route.with_points($value)
+    };
+    ($route:ident, thumbnail, $value:expr) => {
+        $route.with_thumbnail($value)
+    };
+    ($route:ident, default, $value:expr) => {
+        $route.with_default($value)
+    };
+    ($route:ident, id, $value:expr) => {
+        $route.with_id(::autons::simple::RouteId::new($value))
+    };
+}
+
+/// Stringifies the last segment of a path for [`route!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __route_name {
+    ($last:ident) => {
+        stringify!($last)
+    };
+    ($first:ident :: $($rest:ident)::+) => {
+        ::autons::__route_name!($($rest)::+)
+    };
+}
+
+/// Concisely creates a [disabled] placeholder [`Route`].
+///
+/// # Example
+///
+/// ```ignore
+/// let routes = [
+///     route!("Route 1", Robot::route_1),
+///     route_disabled!("TBD"),
+/// ];
+/// ```
+///
+/// [disabled]: Route::disabled
+#[macro_export]
+macro_rules! route_disabled {
+    ($name:expr) => {{ ::autons::simple::Route::disabled($name) }};
+}
+pub use route_disabled;
+
+/// Creates a [mirrored pair] of routes named `"<name> Red"` and `"<name> Blue"`.
+///
+/// The route function takes the [`Alliance`] as an extra argument. When the name is a string
+/// literal, the pair is built without allocating, so it can be used in a `const` array of routes.
+///
+/// # Example
+///
+/// ```ignore
+/// impl Robot {
+///     async fn left(&mut self, alliance: Alliance) {
+///         self.turn(alliance.mirror(90.0)).await;
+///         // ...
+///     }
+/// }
+///
+/// const ROUTES: [Route<Robot>; 2] = route_mirrored!("Left", Robot::left);
+/// ```
+///
+/// [mirrored pair]: Route::mirrored_pair
+/// [`Alliance`]: crate::simple::Alliance
+#[macro_export]
+macro_rules! route_mirrored {
+    ($name:literal, $func:path) => {{
+        ::autons::simple::Route::mirrored_pair(
+            [concat!($name, " Red"), concat!($name, " Blue")],
+            |robot, alliance| ::std::boxed::Box::pin($func(robot, alliance)),
+        )
+    }};
+    ($name:expr, $func:path) => {{
+        let name = $name;
+        let [red, blue] = ::autons::simple::Route::mirrored_pair(["", ""], |robot, alliance| {
+            ::std::boxed::Box::pin($func(robot, alliance))
+        });
+
+        [
+            red.with_name(format!("{name} Red")),
+            blue.with_name(format!("{name} Blue")),
+        ]
+    }};
+}
+pub use route_mirrored;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use super::Route;
+
+    type Log = Vec<&'static str>;
+
+    #[test]
+    fn sequence_runs_setup_and_teardown_of_each_route() {
+        let first = Route::<Log>::new("First", |log| Box::pin(async { log.push("first") }))
+            .with_setup(|log| Box::pin(async { log.push("first setup") }))
+            .with_teardown(|log| Box::pin(async { log.push("first teardown") }));
+        let second = Route::<Log>::new("Second", |log| Box::pin(async { log.push("second") }))
+            .with_setup(|log| Box::pin(async { log.push("second setup") }));
+        let sequence = Route::sequence("Sequence", [first, second]);
+
+        let mut log = Vec::new();
+        {
+            let run = pin!(sequence.run(&mut log));
+            assert!(matches!(
+                run.poll(&mut Context::from_waker(Waker::noop())),
+                Poll::Ready(_)
+            ));
+        }
+
+        assert_eq!(
+            log,
+            [
+                "first setup",
+                "first",
+                "first teardown",
+                "second setup",
+                "second",
+            ]
+        );
+    }
+}
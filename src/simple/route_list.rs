@@ -0,0 +1,111 @@
+use std::cmp::Ordering;
+
+use super::Route;
+
+/// An ordered list of routes, with helpers for arranging them before they're given to a selector.
+///
+/// Reordering routes doesn't change their [IDs](Route::id), so selections persisted with
+/// [`SimpleSelectBuilder::persist_to`] still resolve to the same route afterwards. Note that
+/// [`SimpleSelect`] still sorts routes by [priority](Route::priority), so these helpers only
+/// decide the order of routes with equal priority.
+///
+/// # Examples
+///
+/// ```ignore
+/// let routes = RouteList::new([
+///     route!("Skills", Robot::skills, category: "Skills"),
+///     route!("Right", Robot::right),
+///     route!("Left", Robot::left, default: true),
+/// ])
+/// .sort_by_name()
+/// .sort_by_category()
+/// .pin_default_first();
+///
+/// let selector = SimpleSelect::new(peripherals.display, routes.into_inner());
+/// ```
+///
+/// [`SimpleSelectBuilder::persist_to`]: super::SimpleSelectBuilder::persist_to
+/// [`SimpleSelect`]: super::SimpleSelect
+pub struct RouteList<R: 'static, const N: usize> {
+    routes: [Route<R>; N],
+}
+
+impl<R, const N: usize> RouteList<R, N> {
+    /// Creates a new list from an array of routes, in their original order.
+    pub const fn new(routes: [Route<R>; N]) -> Self {
+        Self { routes }
+    }
+
+    /// Sorts the routes alphabetically by name, ignoring case.
+    ///
+    /// This is a stable sort, so routes with the same name keep their order.
+    #[must_use]
+    pub fn sort_by_name(mut self) -> Self {
+        self.routes
+            .sort_by(|a, b| compare_ignore_case(&a.name, &b.name));
+        self
+    }
+
+    /// Groups the routes by [category](Route::category), with categories sorted alphabetically.
+    ///
+    /// Routes without a category come first. This is a stable sort, so routes in the same
+    /// category keep their order, allowing it to be combined with [`RouteList::sort_by_name`].
+    #[must_use]
+    pub fn sort_by_category(mut self) -> Self {
+        self.routes.sort_by(|a, b| match (a.category, b.category) {
+            (Some(a), Some(b)) => compare_ignore_case(a, b),
+            (a, b) => a.is_some().cmp(&b.is_some()),
+        });
+        self
+    }
+
+    /// Moves the route at `index` to the front of the list, keeping the order of the others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[must_use]
+    pub fn pin_first(mut self, index: usize) -> Self {
+        assert!(index < N, "Invalid route index.");
+        self.routes[..=index].rotate_right(1);
+        self
+    }
+
+    /// Moves the [default route](Route::is_default) to the front of the list, if there is one.
+    #[must_use]
+    pub fn pin_default_first(self) -> Self {
+        match self.routes.iter().position(|route| route.is_default) {
+            Some(index) => self.pin_first(index),
+            None => self,
+        }
+    }
+
+    /// Returns the routes in the list.
+    pub const fn routes(&self) -> &[Route<R>; N] {
+        &self.routes
+    }
+
+    /// Unwraps the list, returning its routes.
+    pub fn into_inner(self) -> [Route<R>; N] {
+        self.routes
+    }
+}
+
+impl<R, const N: usize> From<[Route<R>; N]> for RouteList<R, N> {
+    fn from(routes: [Route<R>; N]) -> Self {
+        Self::new(routes)
+    }
+}
+
+impl<R, const N: usize> From<RouteList<R, N>> for [Route<R>; N] {
+    fn from(list: RouteList<R, N>) -> Self {
+        list.into_inner()
+    }
+}
+
+/// Compares two strings alphabetically, ignoring ASCII case.
+fn compare_ignore_case(a: &str, b: &str) -> Ordering {
+    a.bytes()
+        .map(|byte| byte.to_ascii_lowercase())
+        .cmp(b.bytes().map(|byte| byte.to_ascii_lowercase()))
+}
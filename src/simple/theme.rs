@@ -1,26 +1,84 @@
+use std::time::Duration;
+
 use vexide::color::Color;
 
+use crate::ColorExt;
+
 /// Default dark theme for [`SimpleSelect`].
 ///
 /// [`SimpleSelect`]: super::SimpleSelect
 pub const THEME_DARK: SimpleSelectTheme = SimpleSelectTheme {
     background_default: Color::new(25, 25, 25),
     background_active: Color::new(102, 102, 102),
-    background_selected: Color::new(67, 189, 224),
+    background_selected: Color::new(34, 139, 186),
     background_selected_active: Color::new(123, 209, 233),
 
     text_default: Color::new(187, 187, 187),
     text_selected: Color::new(255, 255, 255),
-    text_active: Color::new(187, 187, 187),
-    text_selected_active: Color::new(255, 255, 255),
+    text_active: Color::new(255, 255, 255),
+    text_selected_active: Color::new(15, 40, 55),
+
+    background_disabled: Color::new(35, 35, 35),
+    text_disabled: Color::new(85, 85, 85),
+
+    background_group_header: Color::new(224, 160, 67),
 
     border: Color::new(153, 153, 153),
+    border_width: 1,
+    border_style: BorderStyle::Solid,
+};
+
+/// Light theme for [`SimpleSelect`].
+///
+/// This is easier to read than [`THEME_DARK`] under bright lights or on camera, such as when
+/// presenting the robot to judges.
+///
+/// [`SimpleSelect`]: super::SimpleSelect
+pub const THEME_LIGHT: SimpleSelectTheme = SimpleSelectTheme {
+    background_default: Color::new(245, 245, 245),
+    background_active: Color::new(205, 205, 205),
+    background_selected: Color::new(25, 110, 200),
+    background_selected_active: Color::new(140, 190, 240),
+
+    text_default: Color::new(45, 45, 45),
+    text_selected: Color::new(255, 255, 255),
+    text_active: Color::new(0, 0, 0),
+    text_selected_active: Color::new(10, 30, 60),
+
+    background_disabled: Color::new(228, 228, 228),
+    text_disabled: Color::new(160, 160, 160),
+
+    background_group_header: Color::new(225, 135, 30),
+
+    border: Color::new(185, 185, 185),
+    border_width: 1,
+    border_style: BorderStyle::Solid,
 };
 
 /// Color theme for the [`SimpleSelect`] autonomous selector.
 ///
+/// Routes with an [accent color] use a faded version of it in place of `background_selected`
+/// when selected. All other colors, including `background_selected_active`, always come from
+/// the theme.
+///
+/// New fields may be added to this struct in the future, so it can't be constructed using a struct
+/// literal outside of this crate. Custom themes can be created with [`SimpleSelectTheme::builder`],
+/// or by modifying the fields of an existing theme such as [`THEME_DARK`].
+///
+/// The `with_*` methods override individual fields of an existing theme, and are `const` so
+/// that the result can be stored in a `const` item. This is the easiest way to change only a few
+/// colors, such as for a team-branded theme:
+///
+/// ```ignore
+/// const THEME_PURPLE: SimpleSelectTheme = THEME_DARK
+///     .with_background_selected(Color::new(128, 0, 255))
+///     .with_background_selected_active(Color::new(170, 100, 255));
+/// ```
+///
 /// [`SimpleSelect`]: super::SimpleSelect
+/// [accent color]: super::Route::accent_color
 #[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct SimpleSelectTheme {
     pub background_default: Color,
     pub background_active: Color,
@@ -32,7 +90,178 @@ pub struct SimpleSelectTheme {
     pub text_selected: Color,
     pub text_selected_active: Color,
 
+    pub background_disabled: Color,
+    pub text_disabled: Color,
+
+    /// Color of the separator drawn above the first route of each [category].
+    ///
+    /// [category]: super::Route::category
+    pub background_group_header: Color,
+
     pub border: Color,
+
+    /// Thickness of the gridlines between routes, in pixels.
+    pub border_width: u16,
+
+    /// Whether the gridlines between routes are solid or dashed.
+    pub border_style: BorderStyle,
+}
+
+impl SimpleSelectTheme {
+    /// Returns a builder for creating a custom theme, starting from the colors of [`THEME_DARK`].
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// const THEME_RED: SimpleSelectTheme = SimpleSelectTheme::builder()
+    ///     .background_selected(Color::new(224, 67, 67))
+    ///     .background_selected_active(Color::new(233, 123, 123))
+    ///     .build();
+    /// ```
+    pub const fn builder() -> SimpleSelectThemeBuilder {
+        SimpleSelectThemeBuilder { theme: THEME_DARK }
+    }
+
+    /// Returns this theme with its `background_default` color replaced.
+    #[must_use]
+    pub const fn with_background_default(mut self, color: Color) -> Self {
+        self.background_default = color;
+        self
+    }
+
+    /// Returns this theme with its `background_active` color replaced.
+    #[must_use]
+    pub const fn with_background_active(mut self, color: Color) -> Self {
+        self.background_active = color;
+        self
+    }
+
+    /// Returns this theme with its `background_selected` color replaced.
+    #[must_use]
+    pub const fn with_background_selected(mut self, color: Color) -> Self {
+        self.background_selected = color;
+        self
+    }
+
+    /// Returns this theme with its `background_selected_active` color replaced.
+    #[must_use]
+    pub const fn with_background_selected_active(mut self, color: Color) -> Self {
+        self.background_selected_active = color;
+        self
+    }
+
+    /// Returns this theme with its `text_default` color replaced.
+    #[must_use]
+    pub const fn with_text_default(mut self, color: Color) -> Self {
+        self.text_default = color;
+        self
+    }
+
+    /// Returns this theme with its `text_active` color replaced.
+    #[must_use]
+    pub const fn with_text_active(mut self, color: Color) -> Self {
+        self.text_active = color;
+        self
+    }
+
+    /// Returns this theme with its `text_selected` color replaced.
+    #[must_use]
+    pub const fn with_text_selected(mut self, color: Color) -> Self {
+        self.text_selected = color;
+        self
+    }
+
+    /// Returns this theme with its `text_selected_active` color replaced.
+    #[must_use]
+    pub const fn with_text_selected_active(mut self, color: Color) -> Self {
+        self.text_selected_active = color;
+        self
+    }
+
+    /// Returns this theme with its `background_disabled` color replaced.
+    #[must_use]
+    pub const fn with_background_disabled(mut self, color: Color) -> Self {
+        self.background_disabled = color;
+        self
+    }
+
+    /// Returns this theme with its `text_disabled` color replaced.
+    #[must_use]
+    pub const fn with_text_disabled(mut self, color: Color) -> Self {
+        self.text_disabled = color;
+        self
+    }
+
+    /// Returns this theme with its `background_group_header` color replaced.
+    #[must_use]
+    pub const fn with_background_group_header(mut self, color: Color) -> Self {
+        self.background_group_header = color;
+        self
+    }
+
+    /// Returns this theme with its `border` color replaced.
+    #[must_use]
+    pub const fn with_border(mut self, color: Color) -> Self {
+        self.border = color;
+        self
+    }
+
+    /// Returns this theme with its `border_width` replaced.
+    #[must_use]
+    pub const fn with_border_width(mut self, width: u16) -> Self {
+        self.border_width = width;
+        self
+    }
+
+    /// Returns this theme with its `border_style` replaced.
+    #[must_use]
+    pub const fn with_border_style(mut self, style: BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Returns this theme with every background color blended with black at the given opacity.
+    ///
+    /// The display can't draw translucent colors, so this simulates drawing the selector over a
+    /// dark camera feed or backdrop. An `alpha` of `0` makes the backgrounds black, and `255`
+    /// leaves them unchanged. Text and border colors are not affected.
+    #[must_use]
+    pub fn with_background_opacity(self, alpha: u8) -> Self {
+        let blend = |color| Color::with_alpha_blend(color, Color::BLACK, alpha);
+
+        Self {
+            background_default: blend(self.background_default),
+            background_active: blend(self.background_active),
+            background_selected: blend(self.background_selected),
+            background_selected_active: blend(self.background_selected_active),
+            background_disabled: blend(self.background_disabled),
+            background_group_header: blend(self.background_group_header),
+            ..self
+        }
+    }
+
+    /// Returns a copy of this theme with every color faded towards the default background by
+    /// `amount`.
+    pub(crate) fn dimmed(&self, amount: f32) -> Self {
+        let dim = |color| Color::lerp(color, self.background_default, amount);
+
+        Self {
+            background_default: self.background_default,
+            background_active: dim(self.background_active),
+            background_selected: dim(self.background_selected),
+            background_selected_active: dim(self.background_selected_active),
+            text_default: dim(self.text_default),
+            text_active: dim(self.text_active),
+            text_selected: dim(self.text_selected),
+            text_selected_active: dim(self.text_selected_active),
+            background_disabled: dim(self.background_disabled),
+            text_disabled: dim(self.text_disabled),
+            background_group_header: dim(self.background_group_header),
+            border: dim(self.border),
+            border_width: self.border_width,
+            border_style: self.border_style,
+        }
+    }
 }
 
 impl Default for SimpleSelectTheme {
@@ -40,3 +269,173 @@ impl Default for SimpleSelectTheme {
         THEME_DARK
     }
 }
+
+/// Builder for creating a custom [`SimpleSelectTheme`].
+///
+/// A builder can be obtained through [`SimpleSelectTheme::builder`]. Any colors that aren't set
+/// are taken from [`THEME_DARK`].
+#[derive(Debug, Eq, PartialEq)]
+pub struct SimpleSelectThemeBuilder {
+    theme: SimpleSelectTheme,
+}
+
+impl SimpleSelectThemeBuilder {
+    /// Sets the theme's `background_default` color.
+    #[must_use]
+    pub const fn background_default(mut self, color: Color) -> Self {
+        self.theme.background_default = color;
+        self
+    }
+
+    /// Sets the theme's `background_active` color.
+    #[must_use]
+    pub const fn background_active(mut self, color: Color) -> Self {
+        self.theme.background_active = color;
+        self
+    }
+
+    /// Sets the theme's `background_selected` color.
+    #[must_use]
+    pub const fn background_selected(mut self, color: Color) -> Self {
+        self.theme.background_selected = color;
+        self
+    }
+
+    /// Sets the theme's `background_selected_active` color.
+    #[must_use]
+    pub const fn background_selected_active(mut self, color: Color) -> Self {
+        self.theme.background_selected_active = color;
+        self
+    }
+
+    /// Sets the theme's `text_default` color.
+    #[must_use]
+    pub const fn text_default(mut self, color: Color) -> Self {
+        self.theme.text_default = color;
+        self
+    }
+
+    /// Sets the theme's `text_active` color.
+    #[must_use]
+    pub const fn text_active(mut self, color: Color) -> Self {
+        self.theme.text_active = color;
+        self
+    }
+
+    /// Sets the theme's `text_selected` color.
+    #[must_use]
+    pub const fn text_selected(mut self, color: Color) -> Self {
+        self.theme.text_selected = color;
+        self
+    }
+
+    /// Sets the theme's `text_selected_active` color.
+    #[must_use]
+    pub const fn text_selected_active(mut self, color: Color) -> Self {
+        self.theme.text_selected_active = color;
+        self
+    }
+
+    /// Sets the theme's `background_disabled` color.
+    #[must_use]
+    pub const fn background_disabled(mut self, color: Color) -> Self {
+        self.theme.background_disabled = color;
+        self
+    }
+
+    /// Sets the theme's `text_disabled` color.
+    #[must_use]
+    pub const fn text_disabled(mut self, color: Color) -> Self {
+        self.theme.text_disabled = color;
+        self
+    }
+
+    /// Sets the theme's `background_group_header` color.
+    #[must_use]
+    pub const fn background_group_header(mut self, color: Color) -> Self {
+        self.theme.background_group_header = color;
+        self
+    }
+
+    /// Sets the theme's `border` color.
+    #[must_use]
+    pub const fn border(mut self, color: Color) -> Self {
+        self.theme.border = color;
+        self
+    }
+
+    /// Sets the theme's `border_width`.
+    #[must_use]
+    pub const fn border_width(mut self, width: u16) -> Self {
+        self.theme.border_width = width;
+        self
+    }
+
+    /// Sets the theme's `border_style`.
+    #[must_use]
+    pub const fn border_style(mut self, style: BorderStyle) -> Self {
+        self.theme.border_style = style;
+        self
+    }
+
+    /// Creates the theme from this configuration.
+    pub const fn build(self) -> SimpleSelectTheme {
+        self.theme
+    }
+}
+
+/// How the gridlines of a [`SimpleSelectTheme`] are drawn.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum BorderStyle {
+    /// A continuous line.
+    #[default]
+    Solid,
+
+    /// A line broken into dashes.
+    Dashed {
+        /// Length of each dash, in pixels.
+        segment: u16,
+
+        /// Length of the space between dashes, in pixels.
+        gap: u16,
+    },
+}
+
+/// A [`SimpleSelectTheme`] that smoothly fades between route background colors.
+///
+/// Instead of changing instantly, a route's background color is blended from its old color to
+/// its new one over [`transition_ms`] milliseconds whenever it is tapped or selected.
+///
+/// [`transition_ms`]: AnimatedTheme::transition_ms
+#[derive(Debug, Eq, PartialEq)]
+pub struct AnimatedTheme {
+    /// The colors used by the selector.
+    pub inner: SimpleSelectTheme,
+
+    /// How long each color transition lasts, in milliseconds.
+    pub transition_ms: u64,
+}
+
+impl AnimatedTheme {
+    /// The default transition length, in milliseconds.
+    pub const DEFAULT_TRANSITION_MS: u64 = 80;
+
+    /// Animates a theme using the default transition length.
+    pub const fn new(inner: SimpleSelectTheme) -> Self {
+        Self {
+            inner,
+            transition_ms: Self::DEFAULT_TRANSITION_MS,
+        }
+    }
+
+    /// Returns the length of each color transition.
+    pub const fn transition(&self) -> Duration {
+        Duration::from_millis(self.transition_ms)
+    }
+}
+
+impl Default for AnimatedTheme {
+    fn default() -> Self {
+        Self::new(THEME_DARK)
+    }
+}
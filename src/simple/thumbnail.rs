@@ -0,0 +1,109 @@
+use vexide::{
+    color::Color,
+    display::{Display, Rect},
+};
+
+/// A small image shown for a route, such as a field diagram of its starting position and path.
+///
+/// Thumbnails are drawn in the detail panel of the [detail layout] when their route is selected.
+///
+/// # Pixel Format
+///
+/// The pixels are raw 8-bit RGB with no header or padding: three bytes per pixel in the order
+/// red, green, blue, with pixels stored row by row from the top-left corner. An image `width`
+/// pixels wide and `height` pixels tall is exactly `width * height * 3` bytes long.
+///
+/// This is the format written by, for example, `convert diagram.png -depth 8 diagram.rgb` with
+/// ImageMagick, so images can be embedded with [`include_bytes!`] without a build script:
+///
+/// ```
+/// use autons::simple::Thumbnail;
+///
+/// const RUSH: Thumbnail = Thumbnail::new(2, 1, &[255, 0, 0, 0, 0, 255]);
+/// # let _ = RUSH;
+/// ```
+///
+/// [detail layout]: super::SimpleSelectLayout::Detail
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Thumbnail {
+    width: u16,
+    height: u16,
+    pixels: &'static [u8],
+}
+
+impl Thumbnail {
+    /// Creates a thumbnail from raw RGB pixels in the [expected format](Thumbnail#pixel-format).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pixels` isn't exactly `width * height * 3` bytes long. In a `const` this is
+    /// reported at compile time.
+    pub const fn new(width: u16, height: u16, pixels: &'static [u8]) -> Self {
+        assert!(
+            pixels.len() == width as usize * height as usize * 3,
+            "Thumbnail pixels must be exactly width * height * 3 bytes of RGB data."
+        );
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Returns the width of the thumbnail in pixels.
+    pub const fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the height of the thumbnail in pixels.
+    pub const fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns the thumbnail's raw RGB pixels.
+    pub const fn pixels(&self) -> &'static [u8] {
+        self.pixels
+    }
+
+    /// Draws the part of the image inside `clip`, with the image's top-left corner at `origin`.
+    ///
+    /// Anything outside of the display is left out.
+    pub(crate) fn draw(&self, display: &mut Display, origin: [i16; 2], clip: Rect) {
+        let [x, y] = origin;
+        let left = clip.top_left.x.max(x).max(0);
+        let top = clip.top_left.y.max(y).max(0);
+        let right = clip
+            .bottom_right
+            .x
+            .min(x + self.width as i16)
+            .min(Display::HORIZONTAL_RESOLUTION);
+        let bottom = clip
+            .bottom_right
+            .y
+            .min(y + self.height as i16)
+            .min(Display::VERTICAL_RESOLUTION);
+
+        if left >= right || top >= bottom {
+            return;
+        }
+
+        let mut colors = Vec::with_capacity((right - left) as usize * (bottom - top) as usize);
+        for row in top - y..bottom - y {
+            let start = (row as usize * self.width as usize + (left - x) as usize) * 3;
+            let end = start + (right - left) as usize * 3;
+
+            colors.extend(
+                self.pixels[start..end]
+                    .chunks_exact(3)
+                    .map(|pixel| Color::new(pixel[0], pixel[1], pixel[2])),
+            );
+        }
+
+        // The SDK mock used for host builds can't copy pixels, so only the brain draws images.
+        #[cfg(target_os = "vexos")]
+        display.draw_buffer(Rect::new([left, top], [right, bottom]), &colors);
+        #[cfg(not(target_os = "vexos"))]
+        let _ = (display, colors);
+    }
+}
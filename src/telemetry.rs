@@ -0,0 +1,150 @@
+use std::{rc::Rc, time::Duration};
+
+/// Receiver for structured events reported by selectors.
+///
+/// All methods have empty default implementations, so implementors only need to handle the
+/// events they care about.
+///
+/// Selectors expose their telemetry receiver through [`Selector::telemetry`], which
+/// [`SelectCompete`] uses to report when routes start and finish.
+///
+/// [`Selector::telemetry`]: crate::Selector::telemetry
+/// [`SelectCompete`]: crate::compete::SelectCompete
+pub trait SelectorTelemetry {
+    /// Called when the selected route changes.
+    fn selection_changed(&self, index: usize, name: &str) {
+        _ = (index, name);
+    }
+
+    /// Called immediately before the selected route starts running.
+    fn route_started(&self) {}
+
+    /// Called after the selected route finishes running, with the time it took to run.
+    fn route_ended(&self, duration: Duration) {
+        _ = duration;
+    }
+
+    /// Called in place of [`route_started`] and [`route_ended`] when the selected route is a
+    /// [no-op route] that does nothing.
+    ///
+    /// [`route_started`]: SelectorTelemetry::route_started
+    /// [`route_ended`]: SelectorTelemetry::route_ended
+    /// [no-op route]: crate::simple::Route::noop
+    fn noop_route_ran(&self) {}
+
+    /// Called after the selected route finishes running, with the time spent in each of its
+    /// phases.
+    ///
+    /// This is reported by selectors that run routes with [setup] and [teardown] functions, in
+    /// addition to [`route_ended`].
+    ///
+    /// [setup]: crate::simple::Route::with_setup
+    /// [teardown]: crate::simple::Route::with_teardown
+    /// [`route_ended`]: SelectorTelemetry::route_ended
+    fn route_timings(&self, timings: RouteTimings) {
+        _ = timings;
+    }
+}
+
+/// Time spent in each phase of a route, reported through [`SelectorTelemetry::route_timings`].
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RouteTimings {
+    /// Time spent in the route's setup function, if it has one.
+    pub setup: Option<Duration>,
+
+    /// Time spent in the route's main function.
+    pub body: Duration,
+
+    /// Time spent in the route's teardown function, if it has one.
+    pub teardown: Option<Duration>,
+
+    /// Whether the route's main function was stopped for exceeding its timeout.
+    pub timed_out: bool,
+}
+
+impl<T: SelectorTelemetry + ?Sized> SelectorTelemetry for Box<T> {
+    fn selection_changed(&self, index: usize, name: &str) {
+        (**self).selection_changed(index, name);
+    }
+
+    fn route_started(&self) {
+        (**self).route_started();
+    }
+
+    fn route_ended(&self, duration: Duration) {
+        (**self).route_ended(duration);
+    }
+
+    fn noop_route_ran(&self) {
+        (**self).noop_route_ran();
+    }
+
+    fn route_timings(&self, timings: RouteTimings) {
+        (**self).route_timings(timings);
+    }
+}
+
+impl<T: SelectorTelemetry + ?Sized> SelectorTelemetry for Rc<T> {
+    fn selection_changed(&self, index: usize, name: &str) {
+        (**self).selection_changed(index, name);
+    }
+
+    fn route_started(&self) {
+        (**self).route_started();
+    }
+
+    fn route_ended(&self, duration: Duration) {
+        (**self).route_ended(duration);
+    }
+
+    fn noop_route_ran(&self) {
+        (**self).noop_route_ran();
+    }
+
+    fn route_timings(&self, timings: RouteTimings) {
+        (**self).route_timings(timings);
+    }
+}
+
+/// Telemetry receiver that prints events to the serial terminal.
+///
+/// Output can be viewed from a connected computer using `cargo v5 run` or `cargo v5 terminal`.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SerialTelemetry;
+
+impl SelectorTelemetry for SerialTelemetry {
+    fn selection_changed(&self, index: usize, name: &str) {
+        println!("[autons] Selected route {index} ({name})");
+    }
+
+    fn route_started(&self) {
+        println!("[autons] Route started");
+    }
+
+    fn route_ended(&self, duration: Duration) {
+        println!("[autons] Route finished in {:.2}s", duration.as_secs_f64());
+    }
+
+    fn noop_route_ran(&self) {
+        println!("[autons] Ran no-op route");
+    }
+
+    fn route_timings(&self, timings: RouteTimings) {
+        if let Some(setup) = timings.setup {
+            println!("[autons] Route setup took {:.2}s", setup.as_secs_f64());
+        }
+
+        println!(
+            "[autons] Route body took {:.2}s",
+            timings.body.as_secs_f64()
+        );
+
+        if let Some(teardown) = timings.teardown {
+            println!(
+                "[autons] Route teardown took {:.2}s",
+                teardown.as_secs_f64()
+            );
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Selector for driving routes from integration tests.
+//!
+//! [`TestSelector`] runs routes from a pre-programmed sequence of indices, allowing tests to pick
+//! specific routes without a display or controller.
+
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+};
+
+use crate::{Selector, SelectorStatus, simple::Route};
+
+/// A selector that runs routes in a pre-programmed order.
+///
+/// Each call to [`Selector::run`] removes the next index from the front of the sequence and
+/// runs the route at that index.
+///
+/// # Examples
+///
+/// ```ignore
+/// let selector = TestSelector::new(
+///     [route!(Robot::left), route!(Robot::right)],
+///     [1, 0],
+/// );
+///
+/// selector.run(&mut robot).await; // Runs `Robot::right`.
+/// selector.run(&mut robot).await; // Runs `Robot::left`.
+/// ```
+pub struct TestSelector<R: 'static, const N: usize> {
+    routes: [Route<R>; N],
+    sequence: RefCell<VecDeque<usize>>,
+    current: Cell<usize>,
+    timed_out: Cell<bool>,
+}
+
+impl<R, const N: usize> TestSelector<R, N> {
+    /// Creates a new selector that runs routes in the order given by `sequence`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sequence` contains an index that is out of bounds.
+    pub fn new(routes: [Route<R>; N], sequence: impl Into<VecDeque<usize>>) -> Self {
+        let sequence = sequence.into();
+        assert!(
+            sequence.iter().all(|&index| index < N),
+            "Invalid route index in TestSelector sequence."
+        );
+
+        Self {
+            routes,
+            sequence: RefCell::new(sequence),
+            current: Cell::new(0),
+            timed_out: Cell::new(false),
+        }
+    }
+
+    /// Returns the index of the route that was run most recently, or `0` if no route has run.
+    ///
+    /// Unlike [`Selector::selected_index`], which returns the route that will run next, this
+    /// looks back at the last run.
+    pub fn last_run_index(&self) -> usize {
+        self.current.get()
+    }
+
+    /// Returns the number of runs left in the sequence.
+    pub fn remaining(&self) -> usize {
+        self.sequence.borrow().len()
+    }
+
+    /// Returns the routes in this selector.
+    pub const fn routes(&self) -> &[Route<R>; N] {
+        &self.routes
+    }
+}
+
+impl<R, const N: usize> Selector<R> for TestSelector<R, N> {
+    /// Runs the next route in the sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sequence is empty.
+    async fn run(&self, robot: &mut R) {
+        let index = self
+            .sequence
+            .borrow_mut()
+            .pop_front()
+            .expect("TestSelector ran out of route indices.");
+        self.current.set(index);
+
+        let timings = self.routes[index].run(robot).await;
+        self.timed_out.set(timings.timed_out);
+    }
+
+    fn status(&self) -> Option<&dyn SelectorStatus<R>> {
+        Some(self)
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        self.sequence.borrow().front().copied()
+    }
+
+    fn name_of(&self, index: usize) -> Option<Cow<'static, str>> {
+        self.routes.get(index).map(|route| route.name.clone())
+    }
+}
+
+impl<R, const N: usize> SelectorStatus<R> for TestSelector<R, N> {
+    fn is_noop(&self) -> bool {
+        self.sequence
+            .borrow()
+            .front()
+            .is_some_and(|&index| self.routes[index].is_noop)
+    }
+
+    fn timed_out(&self) -> bool {
+        self.timed_out.get()
+    }
+}
@@ -0,0 +1,148 @@
+//! Utilities for testing routes off the robot.
+//!
+//! [`RouteHarness`] runs a single [`Route`] against a mock robot on the host, making it possible
+//! to catch routes that never finish or panic before they're uploaded to a Brain.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use std::time::Duration;
+//!
+//! use autons::{simple::route, testing::RouteHarness};
+//!
+//! #[test]
+//! fn left_awp_completes() {
+//!     let mut harness = RouteHarness::new(route!(Robot::left_awp), Robot::mock());
+//!     harness.assert_completes_within(Duration::from_secs(15));
+//! }
+//! ```
+
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    pin::pin,
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use crate::simple::Route;
+
+/// Result of running a route in a [`RouteHarness`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum RouteOutcome {
+    /// The route finished, taking the given amount of time.
+    Completed(Duration),
+
+    /// The route panicked while running.
+    Panicked,
+
+    /// The route did not finish within its time budget.
+    TimedOut,
+}
+
+impl fmt::Display for RouteOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Completed(duration) => {
+                write!(f, "completed in {:.2}s", duration.as_secs_f64())
+            }
+            Self::Panicked => write!(f, "panicked"),
+            Self::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+/// Runs a route against a mock robot on the host.
+///
+/// The route's future, including its setup and teardown, is polled on the current thread until it
+/// finishes, panics, or exceeds its time budget. A route that is stopped by its own
+/// [timeout](Route::timeout) is also reported as timed out. The budget is checked between polls,
+/// so a route that blocks without ever yielding (such as a `loop {}` with no `.await` inside)
+/// can't be interrupted.
+pub struct RouteHarness<R: 'static> {
+    route: Route<R>,
+    robot: R,
+    outcome: Option<RouteOutcome>,
+}
+
+impl<R> RouteHarness<R> {
+    /// Creates a new harness for running `route` on `robot`.
+    pub fn new(route: Route<R>, robot: R) -> Self {
+        Self {
+            route,
+            robot,
+            outcome: None,
+        }
+    }
+
+    /// Runs the route with the given time budget, returning how it finished.
+    pub fn run(&mut self, budget: Duration) -> RouteOutcome {
+        let mut context = Context::from_waker(Waker::noop());
+        let mut future = pin!(self.route.run(&mut self.robot));
+        let start = Instant::now();
+
+        let outcome = loop {
+            match panic::catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(&mut context))) {
+                Ok(Poll::Ready(timings)) if timings.timed_out => break RouteOutcome::TimedOut,
+                Ok(Poll::Ready(_)) => break RouteOutcome::Completed(start.elapsed()),
+                Ok(Poll::Pending) if start.elapsed() > budget => break RouteOutcome::TimedOut,
+                Ok(Poll::Pending) => {}
+                Err(_) => break RouteOutcome::Panicked,
+            }
+        };
+
+        self.outcome = Some(outcome);
+        outcome
+    }
+
+    /// Returns how the route finished the last time it was run, or `None` if it hasn't been run.
+    pub fn outcome(&self) -> Option<RouteOutcome> {
+        self.outcome
+    }
+
+    /// Runs the route and asserts that it finishes within `budget`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the route panics or does not finish in time.
+    #[track_caller]
+    pub fn assert_completes_within(&mut self, budget: Duration) {
+        let outcome = self.run(budget);
+        assert!(
+            matches!(outcome, RouteOutcome::Completed(_)),
+            "Route `{}` did not complete within {:.2}s: {outcome}.",
+            self.route.name,
+            budget.as_secs_f64(),
+        );
+    }
+
+    /// Runs the route and asserts that it panics within `budget`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the route finishes or times out instead.
+    #[track_caller]
+    pub fn assert_panics(&mut self, budget: Duration) {
+        let outcome = self.run(budget);
+        assert!(
+            outcome == RouteOutcome::Panicked,
+            "Route `{}` was expected to panic, but {outcome}.",
+            self.route.name,
+        );
+    }
+
+    /// Returns a reference to the mock robot.
+    pub fn robot(&self) -> &R {
+        &self.robot
+    }
+
+    /// Returns a mutable reference to the mock robot.
+    pub fn robot_mut(&mut self) -> &mut R {
+        &mut self.robot
+    }
+
+    /// Consumes the harness, returning the mock robot.
+    pub fn into_robot(self) -> R {
+        self.robot
+    }
+}